@@ -0,0 +1,66 @@
+//! Animation implementation for [`arrayvec`] stack-allocated vectors.
+
+use arrayvec::ArrayVec;
+
+use crate::Animable;
+
+/// Elements are interpolated pairwise, like the `[T; N]` implementation.
+///
+/// NOTE: Both vectors are expected to have the same length. If they don't, the result will have
+/// the length of the shortest one, the extra elements being dropped.
+impl<T: Animable, const N: usize> Animable for ArrayVec<T, N> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start
+            .iter()
+            .zip(end)
+            .map(|(start, end)| T::lerp(start, end, progress))
+            .collect()
+    }
+
+    fn lerp_into(start: &Self, end: &Self, progress: f64, out: &mut Self) {
+        let len = start.len().min(end.len());
+        out.truncate(len);
+
+        let filled = out.len();
+        for ((start, end), out) in start.iter().zip(end).zip(out.iter_mut()) {
+            T::lerp_into(start, end, progress, out);
+        }
+        out.extend(
+            start[filled..len]
+                .iter()
+                .zip(&end[filled..len])
+                .map(|(start, end)| T::lerp(start, end, progress)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_lengths_lerp_pairwise() {
+        let start = ArrayVec::<f64, 4>::from_iter([0.0, 10.0, -2.0]);
+        let end = ArrayVec::<f64, 4>::from_iter([1.0, 20.0, 2.0]);
+        let expected = [0.5, 15.0, 0.0];
+        assert_eq!(ArrayVec::lerp(&start, &end, 0.5).as_slice(), expected);
+
+        // Stale elements in the output are overwritten, missing ones are pushed.
+        for mut out in [[9.0; 4].into_iter().collect(), ArrayVec::new()] {
+            ArrayVec::lerp_into(&start, &end, 0.5, &mut out);
+            assert_eq!(out.as_slice(), expected);
+        }
+    }
+
+    #[test]
+    fn mismatched_lengths_keep_the_shortest() {
+        let start = ArrayVec::<f64, 4>::from_iter([0.0, 10.0, -2.0, 7.0]);
+        let end = ArrayVec::<f64, 4>::from_iter([1.0, 20.0]);
+        assert_eq!(ArrayVec::lerp(&start, &end, 0.5).as_slice(), [0.5, 15.0]);
+        assert_eq!(ArrayVec::lerp(&end, &start, 0.5).as_slice(), [0.5, 15.0]);
+
+        let mut out = start.clone();
+        ArrayVec::lerp_into(&start, &end, 0.5, &mut out);
+        assert_eq!(out.as_slice(), [0.5, 15.0]);
+    }
+}
@@ -0,0 +1,112 @@
+//! Animation implementation for [`cgmath`] math types.
+
+use cgmath::{Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+use crate::Animable;
+
+impl<S: Animable> Animable for Vector2<S> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            S::lerp(&start.x, &end.x, progress),
+            S::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl<S: Animable> Animable for Vector3<S> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            S::lerp(&start.x, &end.x, progress),
+            S::lerp(&start.y, &end.y, progress),
+            S::lerp(&start.z, &end.z, progress),
+        )
+    }
+}
+
+impl<S: Animable> Animable for Vector4<S> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            S::lerp(&start.x, &end.x, progress),
+            S::lerp(&start.y, &end.y, progress),
+            S::lerp(&start.z, &end.z, progress),
+            S::lerp(&start.w, &end.w, progress),
+        )
+    }
+}
+
+impl<S: Animable> Animable for Point2<S> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            S::lerp(&start.x, &end.x, progress),
+            S::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl<S: Animable> Animable for Point3<S> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            S::lerp(&start.x, &end.x, progress),
+            S::lerp(&start.y, &end.y, progress),
+            S::lerp(&start.z, &end.z, progress),
+        )
+    }
+}
+
+/// Rotations are spherically interpolated, see [`Animable`].
+impl Animable for Quaternion<f32> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.slerp(*end, progress as f32)
+    }
+}
+
+/// Rotations are spherically interpolated, see [`Animable`].
+impl Animable for Quaternion<f64> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.slerp(*end, progress)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{InnerSpace, Rad, Rotation3};
+
+    use super::*;
+
+    #[test]
+    fn vectors_and_points_lerp_components() {
+        let value = <Vector3<f32> as Animable>::lerp(
+            &Vector3::new(0.0, 10.0, -4.0),
+            &Vector3::new(10.0, 0.0, 4.0),
+            0.25,
+        );
+        assert_eq!(value, Vector3::new(2.5, 7.5, -2.0));
+
+        let value =
+            <Point2<f64> as Animable>::lerp(&Point2::new(1.0, 1.0), &Point2::new(3.0, -1.0), 0.5);
+        assert_eq!(value, Point2::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn quaternion_slerps() {
+        let start = Quaternion::<f64>::from_angle_z(Rad(0.0));
+        let end = Quaternion::from_angle_z(Rad(std::f64::consts::FRAC_PI_2));
+        let middle = <Quaternion<f64> as Animable>::lerp(&start, &end, 0.5);
+        // Spherical interpolation keeps a unit quaternion at a constant angular velocity.
+        assert!((middle.magnitude() - 1.0).abs() < 1e-9);
+        let expected = Quaternion::from_angle_z(Rad(std::f64::consts::FRAC_PI_4));
+        assert!((middle - expected).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn quaternion_extrapolates_along_the_arc() {
+        let start = Quaternion::<f64>::from_angle_z(Rad(0.0));
+        let end = Quaternion::from_angle_z(Rad(std::f64::consts::FRAC_PI_2));
+        let past = <Quaternion<f64> as Animable>::lerp(&start, &end, 1.5);
+        let expected = Quaternion::from_angle_z(Rad(3.0 * std::f64::consts::FRAC_PI_4));
+        assert!((past - expected).magnitude() < 1e-9, "{past:?}");
+        let before = <Quaternion<f64> as Animable>::lerp(&start, &end, -0.5);
+        let expected = Quaternion::from_angle_z(Rad(-std::f64::consts::FRAC_PI_4));
+        assert!((before - expected).magnitude() < 1e-9, "{before:?}");
+    }
+}
@@ -0,0 +1,194 @@
+//! Color helpers for animations.
+//!
+//! Some color transitions look wrong when interpolated component-wise in RGB space. This module
+//! provides helpers and wrappers to animate colors correctly.
+
+#[cfg(feature = "iced")]
+use iced::Color;
+
+use crate::Animable;
+
+/// A color packed as 8-bit RGBA channels.
+///
+/// Each channel is interpolated in floating point space and rounded to the nearest value, instead
+/// of being truncated like integers are. The channels saturate at `0` and `255` if the progress
+/// overshoots/undershoots.
+///
+/// ```rust
+/// use fht_animation::color::Rgba8;
+/// use fht_animation::Animable;
+///
+/// let color = Rgba8::lerp(&Rgba8([0, 0, 0, 255]), &Rgba8([255, 128, 1, 255]), 0.5);
+/// assert_eq!(color, Rgba8([128, 64, 1, 255]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgba8(pub [u8; 4]);
+
+impl Animable for Rgba8 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self(std::array::from_fn(|i| {
+            let channel = f32::lerp(&f32::from(start.0[i]), &f32::from(end.0[i]), progress);
+            // float to int casts saturate.
+            channel.round() as u8
+        }))
+    }
+}
+
+/// Convert a color temperature in Kelvin to an RGB color.
+///
+/// A night-light transition between 2700K and 6500K should go through the blackbody colors, and
+/// not in a straight line in RGB space. In these cases, animate the color temperature instead, and
+/// convert it each frame.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fht_animation::color::kelvin_to_color;
+/// use fht_animation::Animation;
+///
+/// let temperature = Animation::new(6500.0, 2700.0, Duration::from_secs(1));
+/// let color = kelvin_to_color(*temperature.value());
+/// ```
+///
+/// This uses Tanner Helland's approximation of the blackbody radiation, which is accurate enough
+/// for temperatures between 1000K and 40000K. The temperature is clamped inside this range.
+#[cfg(feature = "iced")]
+pub fn kelvin_to_color(kelvin: f64) -> Color {
+    let temperature = kelvin.clamp(1000.0, 40000.0) / 100.0;
+
+    let red = if temperature <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (temperature - 60.0).powf(-0.1332047592)
+    };
+
+    let green = if temperature <= 66.0 {
+        99.4708025861 * temperature.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (temperature - 60.0).powf(-0.0755148492)
+    };
+
+    let blue = if temperature >= 66.0 {
+        255.0
+    } else if temperature <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (temperature - 10.0).ln() - 305.0447927307
+    };
+
+    let normalize = |channel: f64| (channel.clamp(0.0, 255.0) / 255.0) as f32;
+    Color::from_rgb(normalize(red), normalize(green), normalize(blue))
+}
+
+/// A color interpolated with premultiplied alpha.
+///
+/// Interpolating colors with differing alpha component-wise gives too much weight to the color of
+/// the transparent endpoint, which shows up as dark fringes when fading from transparent black,
+/// for example. This wrapper premultiplies the RGB channels by alpha, interpolates, then divides
+/// them back, so that the RGB channels of a fully transparent endpoint don't matter.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use fht_animation::color::PremultipliedColor;
+/// use fht_animation::Animation;
+/// use iced::Color;
+///
+/// let overlay = Animation::new(
+///     PremultipliedColor(Color::TRANSPARENT),
+///     PremultipliedColor(Color::WHITE),
+///     Duration::from_millis(200),
+/// );
+/// let color: Color = overlay.value().0;
+/// ```
+#[cfg(feature = "iced")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PremultipliedColor(pub Color);
+
+#[cfg(feature = "iced")]
+impl Animable for PremultipliedColor {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        let (start, end) = (start.0, end.0);
+        let a = f32::lerp(&start.a, &end.a, progress);
+        let channel = |start_channel: f32, end_channel: f32| {
+            if a <= 0.0 {
+                // Fully transparent, the color doesn't matter.
+                return 0.0;
+            }
+
+            let premultiplied =
+                f32::lerp(&(start_channel * start.a), &(end_channel * end.a), progress);
+            premultiplied / a
+        };
+
+        Self(Color {
+            r: channel(start.r, end.r),
+            g: channel(start.g, end.g),
+            b: channel(start.b, end.b),
+            a,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "iced"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kelvin_reference_values() {
+        let close = |color: Color, [r, g, b]: [f32; 3]| {
+            (color.r - r).abs() < 0.01 && (color.g - g).abs() < 0.01 && (color.b - b).abs() < 0.01
+        };
+        let cases = [
+            (1000.0, [1.0, 0.266, 0.0]),
+            (2700.0, [1.0, 0.654, 0.343]),
+            // Daylight is close to white.
+            (6500.0, [1.0, 0.997, 0.981]),
+            (40000.0, [0.595, 0.728, 1.0]),
+        ];
+        for (kelvin, expected) in cases {
+            let color = kelvin_to_color(kelvin);
+            assert!(close(color, expected), "{kelvin}K: {color:?}");
+            assert_eq!(color.a, 1.0);
+        }
+
+        // Temperatures outside of the approximation range are clamped.
+        assert_eq!(kelvin_to_color(500.0), kelvin_to_color(1000.0));
+        assert_eq!(kelvin_to_color(100000.0), kelvin_to_color(40000.0));
+    }
+
+    #[test]
+    fn premultiplied_midpoint_keeps_the_opaque_color() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let middle = PremultipliedColor::lerp(
+            &PremultipliedColor(Color::TRANSPARENT),
+            &PremultipliedColor(red),
+            0.5,
+        )
+        .0;
+        assert_eq!(
+            (middle.r, middle.g, middle.b, middle.a),
+            (1.0, 0.0, 0.0, 0.5)
+        );
+
+        // Interpolating the straight channels darkens the midpoint towards transparent black.
+        let straight = <Color as Animable>::lerp(&Color::TRANSPARENT, &red, 0.5);
+        assert_eq!(
+            (straight.r, straight.g, straight.b, straight.a),
+            (0.5, 0.0, 0.0, 0.5)
+        );
+
+        // A fully transparent result has no meaningful color, so it's transparent black.
+        let start = PremultipliedColor(Color::from_rgba(0.2, 0.4, 0.6, 0.0));
+        let end = PremultipliedColor(Color::from_rgba(1.0, 1.0, 1.0, 0.0));
+        assert_eq!(
+            PremultipliedColor::lerp(&start, &end, 0.5).0,
+            Color::TRANSPARENT
+        );
+        // Overshooting past a transparent end doesn't divide by a negative alpha either.
+        let end = PremultipliedColor(Color::TRANSPARENT);
+        let overshoot = PremultipliedColor::lerp(&PremultipliedColor(red), &end, 1.5).0;
+        assert_eq!((overshoot.r, overshoot.g, overshoot.b), (0.0, 0.0, 0.0));
+        assert!(overshoot.a < 0.0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::AnimationCurve;
+use crate::{Animable, AnimationCurve, AnimationError};
 
 /// A single cubic control point.
 pub type ControlPoint = (f64, f64);
@@ -14,7 +14,7 @@ pub const BAKED_POINTS: usize = 255;
 ///
 /// This animation has only two exposed control points, since the first and the last onces are
 /// always set to `(0,0)` and `(1,1)` to ensure consistency in values.
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
 pub struct Curve {
@@ -27,6 +27,23 @@ pub struct Curve {
     baked_points: [ControlPoint; BAKED_POINTS],
 }
 
+// Baked points are an implementation detail, and would flood the output.
+impl std::fmt::Debug for Curve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Curve")
+            .field("p1", &self.p1)
+            .field("p2", &self.p2)
+            .finish_non_exhaustive()
+    }
+}
+
+// Baked points are derived from the control points.
+impl PartialEq for Curve {
+    fn eq(&self, other: &Self) -> bool {
+        self.p1 == other.p1 && self.p2 == other.p2
+    }
+}
+
 impl Into<AnimationCurve> for Curve {
     fn into(self) -> AnimationCurve {
         AnimationCurve::Cubic(self)
@@ -91,8 +108,33 @@ impl<'de> serde::Deserialize<'de> for Curve {
 }
 
 impl Curve {
+    /// Check that the control points are finite, and their X coordinates in `[0.0, 1.0]`.
+    pub(crate) fn validate(&self) -> Result<(), AnimationError> {
+        let points = [self.p1, self.p2];
+        if points
+            .iter()
+            .any(|&(x, y)| !x.is_finite() || !y.is_finite())
+        {
+            return Err(AnimationError::NonFiniteParameter);
+        }
+        if points.iter().any(|&(x, _)| !(0.0..=1.0).contains(&x)) {
+            return Err(AnimationError::ControlPointOutOfRange);
+        }
+
+        Ok(())
+    }
+
     /// Create a new cubic animation
-    pub fn new((x0, y0): ControlPoint, (x1, y1): ControlPoint) -> Self {
+    ///
+    /// The control points can be given as tuples or as `[f64; 2]` arrays.
+    ///
+    /// ```rust
+    /// # use fht_animation::CubicCurve;
+    /// let curve = CubicCurve::new([0.25, 0.1], [0.25, 1.0]);
+    /// assert_eq!(curve, CubicCurve::new((0.25, 0.1), (0.25, 1.0)));
+    /// ```
+    pub fn new(p1: impl Into<ControlPoint>, p2: impl Into<ControlPoint>) -> Self {
+        let ((x0, y0), (x1, y1)) = (p1.into(), p2.into());
         let mut baked_points = [ControlPoint::default(); BAKED_POINTS];
 
         let get_x_for_t = |t: f64| {
@@ -114,32 +156,148 @@ impl Curve {
         }
     }
 
+    /// Create a new cubic animation from its control points as arrays, like CSS
+    /// `cubic-bezier(x1, y1, x2, y2)` values.
+    ///
+    /// ```rust
+    /// # use fht_animation::CubicCurve;
+    /// let curve = CubicCurve::from_array([[0.25, 0.1], [0.25, 1.0]]);
+    /// assert_eq!(curve.p1, (0.25, 0.1));
+    /// ```
+    pub fn from_array([p1, p2]: [[f64; 2]; 2]) -> Self {
+        Self::new(p1, p2)
+    }
+
+    /// Create a curve by interpolating the control points of two curves.
+    ///
+    /// This interpolates the curve definitions themselves rather than sampling them, which is
+    /// useful to morph between two curves.
+    pub fn lerp_control_points(a: &Self, b: &Self, progress: f64) -> Self {
+        let lerp_point = |(x0, y0): ControlPoint, (x1, y1): ControlPoint| {
+            (f64::lerp(&x0, &x1, progress), f64::lerp(&y0, &y1, progress))
+        };
+
+        Self::new(lerp_point(a.p1, b.p1), lerp_point(a.p2, b.p2))
+    }
+
     /// Get a value `y` inside `[0, 1]` for a `x` value ranging in `[0, 1]`
+    ///
+    /// The curve goes exactly through `(0, 0)` and `(1, 1)`. Outside of `[0, 1]`, the first or
+    /// last segment of the curve is extrapolated.
+    ///
+    /// ```rust
+    /// # use fht_animation::CubicCurve;
+    /// let curve = CubicCurve::new((0.25, 0.1), (0.25, 1.0));
+    /// assert_eq!(curve.y(0.0), 0.0);
+    /// assert_eq!(curve.y(1.0), 1.0);
+    /// assert!(curve.y(0.9999) <= 1.0 && curve.y(0.9999) > 0.999);
+    /// ```
     pub fn y(&self, x: f64) -> f64 {
-        let mut index = 0;
-        let mut below = true;
-
-        let mut step = (BAKED_POINTS + 1) / 2;
-        while step > 0 {
-            if below {
-                index += step;
-            } else {
-                index -= step;
-            }
-
-            below = self.baked_points[index].0 < x;
-            step /= 2;
+        // Interpolating could be off by a rounding error, and animations must land on their ends.
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return 1.0;
         }
 
-        let lower_index = index.saturating_sub((!below || index == BAKED_POINTS - 1) as usize);
-        let (x0, y0) = self.baked_points[lower_index];
-        let (x1, y1) = self.baked_points[lower_index + 1];
+        // Index of the first baked point at or after x, the segment containing x ends there. When
+        // x is past the last point, use the last segment.
+        let end = self
+            .baked_points
+            .partition_point(|&(px, _)| px < x)
+            .min(BAKED_POINTS - 1);
+        let (x0, y0) = match end {
+            0 => (0.0, 0.0),
+            _ => self.baked_points[end - 1],
+        };
+        let (x1, y1) = self.baked_points[end];
         let delta = (x - x0) / (x1 - x0);
 
         if delta.is_nan() || delta.is_infinite() {
-            0.0
+            // Both points share the same x, the curve is vertical here.
+            y0
         } else {
             y0 + (y1 - y0) * delta
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_omits_baked_points() {
+        let curve = Curve::new((0.25, 0.1), (0.25, 1.0));
+        let debug = format!("{curve:?}");
+        assert_eq!(debug, "Curve { p1: (0.25, 0.1), p2: (0.25, 1.0), .. }");
+        assert!(!debug.contains("baked_points"));
+
+        let animation =
+            crate::Animation::new(0.0, 1.0, std::time::Duration::ZERO).with_curve(curve);
+        let debug = format!("{animation:?}");
+        assert!(debug.contains("curve: Cubic(Curve { p1: (0.25, 0.1), p2: (0.25, 1.0), .. })"));
+        assert!(!debug.contains("baked_points"));
+    }
+
+    #[test]
+    fn morph_matches_its_ends() {
+        let a = Curve::new((0.25, 0.1), (0.25, 1.0));
+        let b = Curve::new((0.42, 0.0), (0.58, 1.0));
+        assert_eq!(Curve::lerp_control_points(&a, &b, 0.0), a);
+        assert_eq!(Curve::lerp_control_points(&a, &b, 1.0), b);
+
+        let middle = Curve::lerp_control_points(&a, &b, 0.5);
+        let close = |(x0, y0): ControlPoint, (x1, y1): ControlPoint| {
+            (x0 - x1).abs() < 1e-12 && (y0 - y1).abs() < 1e-12
+        };
+        assert!(close(middle.p1, (0.335, 0.05)) && close(middle.p2, (0.415, 1.0)));
+        // The baked points are recomputed for the morphed curve too.
+        let morphed = Curve::lerp_control_points(&a, &b, 1.0);
+        for x in [0.1, 0.37, 0.5, 0.82] {
+            assert_eq!(morphed.y(x), b.y(x));
+        }
+    }
+
+    #[test]
+    fn y_at_the_boundaries() {
+        // Solve the bezier directly, to check that y() interpolates in the right segment.
+        let exact = |curve: &Curve, x: f64| {
+            let bezier = |t: f64, p1: f64, p2: f64| {
+                3.0 * (1.0 - t).powi(2) * t * p1 + 3.0 * (1.0 - t) * t.powi(2) * p2 + t.powi(3)
+            };
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..64 {
+                let t = (low + high) / 2.0;
+                if bezier(t, curve.p1.0, curve.p2.0) < x {
+                    low = t;
+                } else {
+                    high = t;
+                }
+            }
+            bezier(high, curve.p1.1, curve.p2.1)
+        };
+
+        let curves = [
+            Curve::new((0.25, 0.1), (0.25, 1.0)),
+            Curve::new((0.42, 0.0), (0.58, 1.0)),
+            Curve::new((0.0, 0.0), (1.0, 1.0)),
+        ];
+        for curve in curves {
+            assert_eq!(curve.y(0.0), 0.0);
+            assert_eq!(curve.y(1.0), 1.0);
+            for x in [0.0001, 0.001, 0.999, 0.9999] {
+                let (y, expected) = (curve.y(x), exact(&curve, x));
+                assert!(
+                    (y - expected).abs() < 1e-4,
+                    "{curve:?} at {x}: {y} != {expected}"
+                );
+            }
+            assert!(curve.y(0.9999) <= 1.0 && curve.y(0.9999) >= curve.y(0.999));
+            // Extrapolating past the ends doesn't go out of bounds.
+            assert!(curve.y(1.0001) >= curve.y(0.9999));
+            assert!(curve.y(-0.0001) <= curve.y(0.0001));
+        }
+    }
+}
@@ -1,11 +1,50 @@
+use std::sync::Arc;
+
 use keyframe::EasingFunction;
 
+use crate::{Animable, AnimationError};
+
 pub mod cubic;
+pub mod piecewise;
 pub mod spring;
 
-#[derive(Debug, Clone, Copy)]
+/// The curve used by an [`Animation`](crate::Animation) to compute its progress.
+///
+/// This enum is non-exhaustive, new curves can be added in minor versions, so your matches need a
+/// catch-all arm.
+///
+/// ```rust
+/// use fht_animation::AnimationCurve;
+///
+/// fn is_spring(curve: &AnimationCurve) -> bool {
+///     match curve {
+///         AnimationCurve::Spring(_) => true,
+///         _ => false,
+///     }
+/// }
+/// assert!(!is_spring(&AnimationCurve::default()));
+/// ```
+///
+/// Matching every variant without a catch-all arm doesn't compile:
+///
+/// ```compile_fail,E0004
+/// use fht_animation::AnimationCurve;
+///
+/// fn kind(curve: &AnimationCurve) -> &'static str {
+///     match curve {
+///         AnimationCurve::Simple(_) => "simple",
+///         AnimationCurve::Spring(_) => "spring",
+///         AnimationCurve::Cubic(_) => "cubic",
+///         AnimationCurve::PiecewiseLinear(_) => "piecewise-linear",
+///         AnimationCurve::BlendEasings { .. } => "blend-easings",
+///         AnimationCurve::Keyframe(_) => "keyframe",
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case", untagged))]
+#[non_exhaustive]
 pub enum AnimationCurve {
     /// Use a preset easing provided by [`keyframe`]
     Simple(Easing),
@@ -13,6 +52,237 @@ pub enum AnimationCurve {
     Spring(spring::Curve),
     /// Use a custom cubic animation with two control points:
     Cubic(cubic::Curve),
+    /// Use a linear interpolation between sampled points.
+    PiecewiseLinear(piecewise::Curve),
+    /// Use a blend between two easings.
+    ///
+    /// The progress is `a.y(x)` interpolated towards `b.y(x)` by `weight`, so a weight of `0.0`
+    /// gives `a` and `1.0` gives `b`. This is useful to expose a single setting that goes from one
+    /// animation feel to another.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use fht_animation::curve::Easing;
+    /// use fht_animation::{Animation, AnimationCurve};
+    ///
+    /// let (a, b) = (Easing::EaseOutCubic, Easing::EaseInOutQuint);
+    /// let value_at_30_percent = |weight| {
+    ///     let curve = AnimationCurve::BlendEasings { a, b, weight };
+    ///     let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1)).with_curve(curve);
+    ///     animation.tick(Duration::from_millis(300));
+    ///     *animation.value()
+    /// };
+    ///
+    /// let close = |x: f64, y: f64| (x - y).abs() < 1e-12;
+    /// assert!(close(value_at_30_percent(0.0), a.y(0.3)));
+    /// assert!(close(value_at_30_percent(1.0), b.y(0.3)));
+    /// assert!(close(value_at_30_percent(0.3), 0.7 * a.y(0.3) + 0.3 * b.y(0.3)));
+    /// ```
+    BlendEasings { a: Easing, b: Easing, weight: f64 },
+    /// Use an arbitrary easing function from [`keyframe`].
+    ///
+    /// See [`AnimationCurve::from_keyframe`]. This curve can't be (de)serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Keyframe(KeyframeEasing),
+}
+
+impl AnimationCurve {
+    /// Create a curve from any [`keyframe`] easing function, including custom ones.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use fht_animation::{Animation, AnimationCurve};
+    /// use keyframe::EasingFunction;
+    ///
+    /// let curve = AnimationCurve::from_keyframe(keyframe::functions::EaseInOut);
+    /// let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1)).with_curve(curve);
+    /// animation.tick(Duration::from_millis(300));
+    /// assert_eq!(*animation.value(), keyframe::functions::EaseInOut.y(0.3));
+    ///
+    /// // A custom easing, going up in four steps.
+    /// struct Steps;
+    /// impl EasingFunction for Steps {
+    ///     fn y(&self, x: f64) -> f64 {
+    ///         (x * 4.0).floor() / 4.0
+    ///     }
+    /// }
+    ///
+    /// let curve = AnimationCurve::from_keyframe(Steps);
+    /// let mut animation = Animation::headless(0.0, 100.0, Duration::from_secs(1)).with_curve(curve);
+    /// animation.tick(Duration::from_millis(600));
+    /// assert_eq!(*animation.value(), 50.0);
+    /// ```
+    pub fn from_keyframe(function: impl EasingFunction + Send + Sync + 'static) -> Self {
+        Self::Keyframe(KeyframeEasing(Arc::new(function)))
+    }
+
+    /// Create a curve from a lookup table of progress values at evenly spaced times.
+    ///
+    /// The first sample is at the start of the animation and the last one at its end, and the
+    /// curve interpolates linearly between them. This is the most direct way to import a curve
+    /// exported as samples by another tool.
+    ///
+    /// This returns [`AnimationError::InvalidPoints`] if `samples` is empty.
+    ///
+    /// ```rust
+    /// use fht_animation::{AnimationCurve, AnimationError};
+    ///
+    /// let curve = AnimationCurve::from_lut(&[0.0, 0.4, 0.7, 0.9, 1.0]);
+    /// assert!(curve.is_ok());
+    /// assert_eq!(AnimationCurve::from_lut(&[]), Err(AnimationError::InvalidPoints));
+    /// ```
+    pub fn from_lut(samples: &[f64]) -> Result<Self, AnimationError> {
+        // Avoid dividing by zero with a single sample, it's held over the whole animation anyway.
+        let last = samples.len().saturating_sub(1).max(1) as f64;
+        let points = samples
+            .iter()
+            .enumerate()
+            .map(|(i, &y)| (i as f64 / last, y))
+            .collect();
+        piecewise::Curve::new(points).map(Self::PiecewiseLinear)
+    }
+
+    /// Sample `n` evenly spaced points of the curve, as `(x, y)`, without clamping `y`.
+    ///
+    /// `x` goes from `0.0` to `1.0`, springs being sampled over their whole duration. Since `y` is
+    /// not clamped, this includes the overshoot of springs and back easings, which is useful to
+    /// draw the true shape of a curve, in a curve editor for example.
+    ///
+    /// See [`AnimationCurve::sample_into`] to reuse a buffer instead.
+    pub fn sample_unclamped(&self, n: usize) -> Vec<(f64, f64)> {
+        let mut samples = vec![(0.0, 0.0); n];
+        self.sample_into(&mut samples);
+        samples
+    }
+
+    /// Fill `out` with evenly spaced points of the curve, as `(x, y)`, without clamping `y`.
+    ///
+    /// This is [`AnimationCurve::sample_unclamped`] with as many samples as `out` can hold, but
+    /// without allocating, which is useful to redraw a curve preview on every frame.
+    ///
+    /// ```rust
+    /// # use fht_animation::AnimationCurve;
+    /// # use fht_animation::curve::Easing;
+    /// let curve = AnimationCurve::Simple(Easing::EaseInOutQuad);
+    /// let mut samples = [(0.0, 0.0); 16];
+    /// curve.sample_into(&mut samples);
+    /// assert_eq!(samples.to_vec(), curve.sample_unclamped(16));
+    /// ```
+    pub fn sample_into(&self, out: &mut [(f64, f64)]) {
+        // Avoid dividing by zero with a single sample.
+        let last = out.len().saturating_sub(1).max(1) as f64;
+        // Computing the spring duration is expensive, only do it once.
+        let spring_duration = match self {
+            Self::Spring(spring) => spring.duration().as_secs_f64(),
+            _ => 0.0,
+        };
+        for (i, sample) in out.iter_mut().enumerate() {
+            let x = i as f64 / last;
+            let y = self.progress(x, x * spring_duration);
+            *sample = (x, y);
+        }
+    }
+
+    /// Get the progress of the curve at `x`, or at `elapsed` seconds for springs.
+    pub(crate) fn progress(&self, x: f64, elapsed: f64) -> f64 {
+        match self {
+            Self::Simple(easing) => easing.y(x),
+            Self::Cubic(cubic) => cubic.y(x),
+            Self::Spring(spring) => spring.oscillate(elapsed),
+            Self::PiecewiseLinear(piecewise) => piecewise.y(x),
+            Self::BlendEasings { a, b, weight } => f64::lerp(&a.y(x), &b.y(x), *weight),
+            Self::Keyframe(function) => function.y(x),
+        }
+    }
+
+    /// Check that the curve parameters are valid.
+    ///
+    /// See [`Animation::validate`](crate::Animation::validate).
+    pub fn validate(&self) -> Result<(), AnimationError> {
+        match self {
+            Self::Simple(easing) => easing.validate(),
+            Self::Spring(spring) => spring.validate(),
+            Self::Cubic(cubic) => cubic.validate(),
+            Self::PiecewiseLinear(piecewise) => piecewise.validate(),
+            Self::BlendEasings { a, b, weight } => {
+                a.validate()?;
+                b.validate()?;
+                if !weight.is_finite() {
+                    return Err(AnimationError::NonFiniteParameter);
+                }
+                Ok(())
+            }
+            // We can't know anything about the function.
+            Self::Keyframe(_) => Ok(()),
+        }
+    }
+
+    /// Check whether this curve has the same configuration as `other`.
+    ///
+    /// Unlike `==`, this ignores the state springs get seeded with when retargeting.
+    pub(crate) fn config_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Spring(spring), Self::Spring(other)) => spring.config_eq(other),
+            _ => self == other,
+        }
+    }
+
+    /// Get a human-readable label for this curve, for example to display in a settings UI.
+    pub fn label(&self) -> String {
+        match self {
+            Self::Simple(easing) => easing.label(),
+            Self::Cubic(cubic) => {
+                let ((x1, y1), (x2, y2)) = (cubic.p1, cubic.p2);
+                format!("Cubic Bezier ({x1}, {y1}, {x2}, {y2})")
+            }
+            Self::Spring(spring) => {
+                // Using f64::EPSILON is too small for this comparaison
+                let damping_ratio = spring.damping_ratio();
+                let kind = if (damping_ratio - 1.0).abs() < f64::from(f32::EPSILON) {
+                    "critically damped"
+                } else if damping_ratio < 1.0 {
+                    "bouncy"
+                } else {
+                    "overdamped"
+                };
+                format!("Spring ({kind})")
+            }
+            Self::PiecewiseLinear(piecewise) => {
+                format!("Piecewise Linear ({} points)", piecewise.points().len())
+            }
+            Self::BlendEasings { a, b, weight } => {
+                format!("Blend ({}, {}, {weight})", a.label(), b.label())
+            }
+            Self::Keyframe(_) => "Custom Easing".to_string(),
+        }
+    }
+}
+
+/// An easing function from [`keyframe`], used by [`AnimationCurve::Keyframe`].
+#[derive(Clone)]
+pub struct KeyframeEasing(Arc<dyn EasingFunction + Send + Sync>);
+
+impl KeyframeEasing {
+    /// Get the Y value at a given X coordinate, assuming that x is included in [0.0, 1.0]
+    pub fn y(&self, x: f64) -> f64 {
+        self.0.y(x)
+    }
+}
+
+// We can't compare functions, so only the same function is equal to itself.
+impl PartialEq for KeyframeEasing {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl std::fmt::Debug for KeyframeEasing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // We can't show anything meaningful about the function itself.
+        f.debug_tuple("KeyframeEasing").finish_non_exhaustive()
+    }
 }
 
 impl Default for AnimationCurve {
@@ -21,15 +291,30 @@ impl Default for AnimationCurve {
     }
 }
 
-/// Wrapper enum including all the easings [`keyframe`] provides.
-#[derive(Default, Debug, Clone, Copy)]
+/// The default overshoot constant used by back easings, giving roughly 10% of overshoot.
+pub const BACK_OVERSHOOT: f64 = 1.70158;
+
+/// Wrapper enum including all the easings [`keyframe`] provides, and some more.
+///
+/// Variants named after a [`keyframe`] function delegate to it. The other ones are implemented
+/// with the formulas from <https://easings.net>, or with the usual polynomials for
+/// [`Easing::SmoothStep`] and [`Easing::SmootherStep`]. The polynomial easings from [`keyframe`]
+/// (quad, cubic, quart and quint) use the same formulas as <https://easings.net>, so all of them
+/// compose cleanly. [`Easing::EaseIn`], [`Easing::EaseOut`] and [`Easing::EaseInOut`] are
+/// [`keyframe`]'s own presets, and don't match any of the polynomial easings.
+///
+/// This enum is non-exhaustive, new easings can be added in minor versions, so your matches need a
+/// catch-all arm.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[non_exhaustive]
 pub enum Easing {
     EaseIn,
     EaseInCubic,
     EaseInOut,
     EaseInOutCubic,
+    EaseInOutQuad,
     EaseInOutQuart,
     EaseInOutQuint,
     EaseInQuad,
@@ -42,6 +327,44 @@ pub enum Easing {
     EaseOutQuint,
     #[default]
     Linear,
+    /// Bounce easing-in.
+    EaseInBounce,
+    /// Bounce easing-out.
+    EaseOutBounce,
+    /// Bounce easing-in, then bounce easing-out.
+    EaseInOutBounce,
+    /// Bounce easing-out, then bounce easing-in.
+    EaseOutInBounce,
+    /// Circular easing-in, then circular easing-out.
+    ///
+    /// Both halves meet exactly at `(0.5, 0.5)`, so there's no kink at the midpoint.
+    ///
+    /// ```rust
+    /// # use fht_animation::curve::Easing;
+    /// let seam = Easing::EaseInOutCirc.y(0.5 - 1e-9) - Easing::EaseInOutCirc.y(0.5 + 1e-9);
+    /// assert!(seam.abs() < 1e-3);
+    /// assert_eq!(Easing::EaseInOutCirc.y(0.5), 0.5);
+    /// ```
+    EaseInOutCirc,
+    /// Circular easing-out, then circular easing-in.
+    EaseOutInCirc,
+    /// Back easing-in with a custom overshoot constant.
+    ///
+    /// The usual value for this constant is [`BACK_OVERSHOOT`], higher values will make the
+    /// animation overshoot further.
+    EaseInBackCustom(f64),
+    /// Back easing-out with a custom overshoot constant.
+    ///
+    /// See [`Easing::EaseInBackCustom`]
+    EaseOutBackCustom(f64),
+    /// Back easing-in-out with a custom overshoot constant.
+    ///
+    /// See [`Easing::EaseInBackCustom`]
+    EaseInOutBackCustom(f64),
+    /// The `smoothstep` function, `3x² - 2x³`.
+    SmoothStep,
+    /// The `smootherstep` function, `6x⁵ - 15x⁴ + 10x³`.
+    SmootherStep,
 }
 
 impl Into<AnimationCurve> for Easing {
@@ -51,9 +374,87 @@ impl Into<AnimationCurve> for Easing {
 }
 
 impl Easing {
+    /// Deserialize an easing, falling back to `default` if the easing name is unknown.
+    ///
+    /// This is useful to keep configuration files working across versions of this crate that
+    /// don't know about the same easings. Only unknown easing *names* fall back, with a warning
+    /// logged through the [`log`] crate, any other invalid value is still an error.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_or<'de, D>(deserializer: D, default: Easing) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Known(Easing),
+            Unknown(String),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Known(easing) => easing,
+            Repr::Unknown(name) => {
+                log::warn!("Unknown easing {name:?}, falling back to {default:?}");
+                default
+            }
+        })
+    }
+
+    /// Get a human-readable label for this easing, for example "Ease In Out Cubic".
+    pub fn label(&self) -> String {
+        let label = match self {
+            Self::EaseIn => "Ease In",
+            Self::EaseInCubic => "Ease In Cubic",
+            Self::EaseInOut => "Ease In Out",
+            Self::EaseInOutCubic => "Ease In Out Cubic",
+            Self::EaseInOutQuad => "Ease In Out Quad",
+            Self::EaseInOutQuart => "Ease In Out Quart",
+            Self::EaseInOutQuint => "Ease In Out Quint",
+            Self::EaseInQuad => "Ease In Quad",
+            Self::EaseInQuart => "Ease In Quart",
+            Self::EaseInQuint => "Ease In Quint",
+            Self::EaseOut => "Ease Out",
+            Self::EaseOutCubic => "Ease Out Cubic",
+            Self::EaseOutQuad => "Ease Out Quad",
+            Self::EaseOutQuart => "Ease Out Quart",
+            Self::EaseOutQuint => "Ease Out Quint",
+            Self::Linear => "Linear",
+            Self::EaseInBounce => "Ease In Bounce",
+            Self::EaseOutBounce => "Ease Out Bounce",
+            Self::EaseInOutBounce => "Ease In Out Bounce",
+            Self::EaseOutInBounce => "Ease Out In Bounce",
+            Self::EaseInOutCirc => "Ease In Out Circ",
+            Self::EaseOutInCirc => "Ease Out In Circ",
+            Self::EaseInBackCustom(c1) => return format!("Ease In Back ({c1})"),
+            Self::EaseOutBackCustom(c1) => return format!("Ease Out Back ({c1})"),
+            Self::EaseInOutBackCustom(c1) => return format!("Ease In Out Back ({c1})"),
+            Self::SmoothStep => "Smooth Step",
+            Self::SmootherStep => "Smoother Step",
+        };
+
+        label.to_string()
+    }
+
+    /// Check that the easing parameters are finite.
+    pub(crate) fn validate(&self) -> Result<(), AnimationError> {
+        match self {
+            Self::EaseInBackCustom(c1)
+            | Self::EaseOutBackCustom(c1)
+            | Self::EaseInOutBackCustom(c1)
+                if !c1.is_finite() =>
+            {
+                Err(AnimationError::NonFiniteParameter)
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Get the Y value at a given X coordinate, assuming that x is included in [0.0, 1.0]
     pub fn y(&self, x: f64) -> f64 {
         match self {
+            // Delegated to keyframe
             Self::EaseIn => keyframe::functions::EaseIn.y(x),
             Self::EaseInCubic => keyframe::functions::EaseInCubic.y(x),
             Self::EaseInOut => keyframe::functions::EaseInOut.y(x),
@@ -69,6 +470,293 @@ impl Easing {
             Self::EaseOutQuart => keyframe::functions::EaseOutQuart.y(x),
             Self::EaseOutQuint => keyframe::functions::EaseOutQuint.y(x),
             Self::Linear => keyframe::functions::Linear.y(x),
+            // Formulas from https://easings.net
+            Self::EaseInOutQuad => {
+                if x < 0.5 {
+                    2.0 * x.powi(2)
+                } else {
+                    1.0 - (-2.0 * x + 2.0).powi(2) / 2.0
+                }
+            }
+            Self::EaseInBounce => 1.0 - bounce_out(1.0 - x),
+            Self::EaseOutBounce => bounce_out(x),
+            // Both halves meet at (0.5, 0.5), since bounce_out(0.0) = 0.0
+            Self::EaseInOutBounce => {
+                if x < 0.5 {
+                    (1.0 - bounce_out(1.0 - 2.0 * x)) / 2.0
+                } else {
+                    (1.0 + bounce_out(2.0 * x - 1.0)) / 2.0
+                }
+            }
+            // Both halves meet at (0.5, 0.5), since bounce_out(1.0) = 1.0
+            Self::EaseOutInBounce => {
+                if x < 0.5 {
+                    bounce_out(2.0 * x) / 2.0
+                } else {
+                    (2.0 - bounce_out(2.0 - 2.0 * x)) / 2.0
+                }
+            }
+            // Both halves meet at (0.5, 0.5), since circ_in(1.0) = 1.0
+            Self::EaseInOutCirc => {
+                if x < 0.5 {
+                    circ_in(2.0 * x) / 2.0
+                } else {
+                    (2.0 - circ_in(2.0 - 2.0 * x)) / 2.0
+                }
+            }
+            // Both halves meet at (0.5, 0.5), since circ_in(0.0) = 0.0
+            Self::EaseOutInCirc => {
+                if x < 0.5 {
+                    (1.0 - circ_in(1.0 - 2.0 * x)) / 2.0
+                } else {
+                    (1.0 + circ_in(2.0 * x - 1.0)) / 2.0
+                }
+            }
+            Self::EaseInBackCustom(c1) => {
+                let c3 = c1 + 1.0;
+                c3 * x.powi(3) - c1 * x.powi(2)
+            }
+            Self::EaseOutBackCustom(c1) => {
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (x - 1.0).powi(3) + c1 * (x - 1.0).powi(2)
+            }
+            Self::EaseInOutBackCustom(c1) => {
+                let c2 = c1 * 1.525;
+                if x < 0.5 {
+                    ((2.0 * x).powi(2) * ((c2 + 1.0) * 2.0 * x - c2)) / 2.0
+                } else {
+                    ((2.0 * x - 2.0).powi(2) * ((c2 + 1.0) * (2.0 * x - 2.0) + c2) + 2.0) / 2.0
+                }
+            }
+            // The usual smoothstep and smootherstep polynomials
+            Self::SmoothStep => x * x * (3.0 - 2.0 * x),
+            Self::SmootherStep => x * x * x * (x * (6.0 * x - 15.0) + 10.0),
         }
     }
 }
+
+/// The bounce easing-out function, which the other bounce easings are derived from.
+fn bounce_out(x: f64) -> f64 {
+    const N1: f64 = 7.5625;
+    const D1: f64 = 2.75;
+
+    if x < 1.0 / D1 {
+        N1 * x * x
+    } else if x < 2.0 / D1 {
+        let x = x - 1.5 / D1;
+        N1 * x * x + 0.75
+    } else if x < 2.5 / D1 {
+        let x = x - 2.25 / D1;
+        N1 * x * x + 0.9375
+    } else {
+        let x = x - 2.625 / D1;
+        N1 * x * x + 0.984375
+    }
+}
+
+/// The circular easing-in function, which the other circular easings are derived from.
+fn circ_in(x: f64) -> f64 {
+    // Clamp the radicand, rounding errors can push it below zero near the ends.
+    1.0 - (1.0 - x * x).max(0.0).sqrt()
+}
+
+/// Deserialize an [`Easing`], falling back to [`Easing::Linear`] if the easing name is unknown.
+///
+/// This is opt-in, use it with `#[serde(deserialize_with = "...")]` on your configuration fields.
+/// See [`Easing::deserialize_or`] for a configurable fallback.
+#[cfg(feature = "serde")]
+pub fn deserialize_easing_lenient<'de, D>(deserializer: D) -> Result<Easing, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Easing::deserialize_or(deserializer, Easing::Linear)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peak(easing: Easing) -> f64 {
+        (0..=1000)
+            .map(|i| easing.y(i as f64 / 1000.0))
+            .fold(f64::MIN, f64::max)
+    }
+
+    #[test]
+    fn larger_back_constant_overshoots_further() {
+        assert!(peak(Easing::EaseOutBackCustom(BACK_OVERSHOOT)) > 1.0);
+        assert!(
+            peak(Easing::EaseOutBackCustom(3.0)) > peak(Easing::EaseOutBackCustom(BACK_OVERSHOOT))
+        );
+        assert!(
+            peak(Easing::EaseInOutBackCustom(3.0))
+                > peak(Easing::EaseInOutBackCustom(BACK_OVERSHOOT))
+        );
+        // Back easing-in undershoots below zero instead.
+        let dip = |c1| {
+            (0..=1000)
+                .map(|i| Easing::EaseInBackCustom(c1).y(i as f64 / 1000.0))
+                .fold(f64::MAX, f64::min)
+        };
+        assert!(dip(3.0) < dip(BACK_OVERSHOOT));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn back_constant_serde_round_trip() {
+        let easing = Easing::EaseOutBackCustom(2.5);
+        let json = serde_json::to_string(&easing).unwrap();
+        assert_eq!(json, r#"{"ease-out-back-custom":2.5}"#);
+        assert_eq!(serde_json::from_str::<Easing>(&json).unwrap(), easing);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn lenient_easing_falls_back_on_unknown_names() {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            #[serde(deserialize_with = "deserialize_easing_lenient")]
+            easing: Easing,
+        }
+        let parse = |json: &str| serde_json::from_str::<Config>(json).map(|config| config.easing);
+
+        assert_eq!(
+            parse(r#"{"easing": "ease-in-out-quad"}"#).unwrap(),
+            Easing::EaseInOutQuad
+        );
+        assert_eq!(
+            parse(r#"{"easing": "ease-out-wobble"}"#).unwrap(),
+            Easing::Linear
+        );
+        // Only names fall back, other invalid values are still errors.
+        assert!(parse(r#"{"easing": 42}"#).is_err());
+        // Without the lenient mode, unknown names are errors.
+        assert!(serde_json::from_str::<Easing>(r#""ease-out-wobble""#).is_err());
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""ease-out-wobble""#);
+        let easing = Easing::deserialize_or(&mut deserializer, Easing::EaseOutCubic).unwrap();
+        assert_eq!(easing, Easing::EaseOutCubic);
+    }
+
+    #[test]
+    fn ease_in_out_quad() {
+        let easing = Easing::EaseInOutQuad;
+        for (x, y) in [
+            (0.0, 0.0),
+            (0.25, 0.125),
+            (0.5, 0.5),
+            (0.75, 0.875),
+            (1.0, 1.0),
+        ] {
+            assert!((easing.y(x) - y).abs() < 1e-12, "y({x}) = {}", easing.y(x));
+        }
+        for i in 0..=50 {
+            let x = i as f64 / 100.0;
+            assert!((easing.y(x) + easing.y(1.0 - x) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn bounce_seams_are_continuous() {
+        for easing in [Easing::EaseInOutBounce, Easing::EaseOutInBounce] {
+            assert_eq!(easing.y(0.5), 0.5);
+            assert!((easing.y(0.5 - 1e-9) - easing.y(0.5 + 1e-9)).abs() < 1e-6);
+            assert!(easing.y(0.0).abs() < 1e-12);
+            assert!((easing.y(1.0) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bounce_serde_round_trip() {
+        for (easing, name) in [
+            (Easing::EaseInBounce, r#""ease-in-bounce""#),
+            (Easing::EaseOutBounce, r#""ease-out-bounce""#),
+            (Easing::EaseInOutBounce, r#""ease-in-out-bounce""#),
+            (Easing::EaseOutInBounce, r#""ease-out-in-bounce""#),
+        ] {
+            assert_eq!(serde_json::to_string(&easing).unwrap(), name);
+            assert_eq!(serde_json::from_str::<Easing>(name).unwrap(), easing);
+        }
+    }
+
+    #[test]
+    fn labels_per_variant() {
+        assert_eq!(AnimationCurve::default().label(), "Linear");
+        let label = AnimationCurve::Simple(Easing::EaseInOutCubic).label();
+        assert_eq!(label, "Ease In Out Cubic");
+        let label = AnimationCurve::Simple(Easing::EaseOutBackCustom(2.5)).label();
+        assert_eq!(label, "Ease Out Back (2.5)");
+
+        let cubic = cubic::Curve::new((0.25, 0.1), (0.25, 1.0));
+        assert_eq!(
+            AnimationCurve::Cubic(cubic).label(),
+            "Cubic Bezier (0.25, 0.1, 0.25, 1)"
+        );
+
+        let spring =
+            |ratio| AnimationCurve::Spring(spring::Curve::new(0.0, false, 1.0, ratio, 300.0, None));
+        assert_eq!(spring(0.3).label(), "Spring (bouncy)");
+        assert_eq!(spring(1.0).label(), "Spring (critically damped)");
+        assert_eq!(spring(1.5).label(), "Spring (overdamped)");
+
+        let label = AnimationCurve::from_lut(&[0.0, 0.4, 0.7, 1.0])
+            .unwrap()
+            .label();
+        assert_eq!(label, "Piecewise Linear (4 points)");
+
+        let blend = AnimationCurve::BlendEasings {
+            a: Easing::EaseOutCubic,
+            b: Easing::Linear,
+            weight: 0.3,
+        };
+        assert_eq!(blend.label(), "Blend (Ease Out Cubic, Linear, 0.3)");
+
+        let label = AnimationCurve::from_keyframe(keyframe::functions::EaseInOut).label();
+        assert_eq!(label, "Custom Easing");
+    }
+
+    #[test]
+    fn smoothstep_polynomials() {
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            let smooth = x * x * (3.0 - 2.0 * x);
+            let smoother = x * x * x * (x * (6.0 * x - 15.0) + 10.0);
+            assert!((Easing::SmoothStep.y(x) - smooth).abs() < 1e-12);
+            assert!((Easing::SmootherStep.y(x) - smoother).abs() < 1e-12);
+        }
+
+        for easing in [Easing::SmoothStep, Easing::SmootherStep] {
+            assert_eq!(easing.y(0.0), 0.0);
+            assert_eq!(easing.y(0.5), 0.5);
+            assert_eq!(easing.y(1.0), 1.0);
+        }
+        // Smootherstep is flatter at both ends.
+        assert!(Easing::SmootherStep.y(0.1) < Easing::SmoothStep.y(0.1));
+        assert!(Easing::SmootherStep.y(0.9) > Easing::SmoothStep.y(0.9));
+    }
+
+    #[test]
+    fn unclamped_samples_keep_the_overshoot() {
+        let outside = |curve: AnimationCurve| {
+            let samples = curve.sample_unclamped(64);
+            let (min, max) = samples
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &(_, y)| {
+                    (min.min(y), max.max(y))
+                });
+            (min < 0.0, max > 1.0)
+        };
+
+        let back_out = AnimationCurve::Simple(Easing::EaseOutBackCustom(1.70158));
+        assert_eq!(outside(back_out), (false, true));
+        let back_in = AnimationCurve::Simple(Easing::EaseInBackCustom(1.70158));
+        assert_eq!(outside(back_in), (true, false));
+        let spring = spring::Curve::new(0.0, false, 1.0, 0.3, 300.0, None);
+        assert_eq!(outside(spring.into()), (false, true));
+
+        // Curves that don't overshoot stay within bounds.
+        let quad = AnimationCurve::Simple(Easing::EaseInOutQuad);
+        assert_eq!(outside(quad), (false, false));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{AnimationCurve, AnimationError};
+
+/// A single sampled point of the curve, as `(x, y)`.
+pub type Point = (f64, f64);
+
+/// Piecewise linear animation curve, interpolating between sampled points.
+///
+/// This is useful to import curves exported as point samples by design tools, since it matches
+/// the exported data exactly. The points should cover the whole `[0.0, 1.0]` range, the curve
+/// holds the first and last `y` values outside of them.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub struct Curve {
+    points: Vec<Point>,
+}
+
+impl From<Curve> for AnimationCurve {
+    fn from(curve: Curve) -> Self {
+        Self::PiecewiseLinear(curve)
+    }
+}
+
+// Custom deserializer to validate the points.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Curve {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Repr {
+            points: Vec<Point>,
+        }
+
+        let Repr { points } = Repr::deserialize(deserializer)?;
+        validate_points(&points).map_err(serde::de::Error::custom)?;
+        Ok(Self { points })
+    }
+}
+
+impl Curve {
+    /// Create a new piecewise linear curve from its points.
+    ///
+    /// This returns [`AnimationError::InvalidPoints`] if `points` is empty, if they are not sorted
+    /// by `x`, or if an `x` is outside of `[0.0, 1.0]`.
+    pub fn new(points: Vec<Point>) -> Result<Self, AnimationError> {
+        validate_points(&points).map_err(|_| AnimationError::InvalidPoints)?;
+        Ok(Self { points })
+    }
+
+    /// Check that the points are finite.
+    ///
+    /// The other invariants are already checked when creating the curve.
+    pub(crate) fn validate(&self) -> Result<(), AnimationError> {
+        if self.points.iter().any(|&(_, y)| !y.is_finite()) {
+            return Err(AnimationError::NonFiniteParameter);
+        }
+
+        Ok(())
+    }
+
+    /// Get the points of this curve.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Get the Y value at a given X coordinate, assuming that x is included in [0.0, 1.0]
+    pub fn y(&self, x: f64) -> f64 {
+        // Index of the first point after x, the segment containing x ends there.
+        let idx = self.points.partition_point(|&(px, _)| px <= x);
+        if idx == 0 {
+            return self.points[0].1;
+        }
+        if idx == self.points.len() {
+            return self.points[idx - 1].1;
+        }
+
+        let (x0, y0) = self.points[idx - 1];
+        let (x1, y1) = self.points[idx];
+        // x1 > x >= x0, so we can't divide by zero here.
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+fn validate_points(points: &[Point]) -> Result<(), &'static str> {
+    if points.is_empty() {
+        return Err("at least one point is required");
+    }
+    if points.iter().any(|&(x, _)| !(0.0..=1.0).contains(&x)) {
+        return Err("x values must be in [0.0, 1.0]");
+    }
+    if points.windows(2).any(|w| w[0].0 > w[1].0) {
+        return Err("points must be sorted by x");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_between_points() {
+        let curve = Curve::new(vec![(0.0, 0.0), (0.5, 0.8), (1.0, 1.0)]).unwrap();
+        assert_eq!(curve.y(0.0), 0.0);
+        assert_eq!(curve.y(0.25), 0.4);
+        assert_eq!(curve.y(0.5), 0.8);
+        assert!((curve.y(0.75) - 0.9).abs() < 1e-12);
+        assert_eq!(curve.y(1.0), 1.0);
+        // The first and last values are held outside of the points.
+        assert_eq!(curve.y(-1.0), 0.0);
+        assert_eq!(curve.y(2.0), 1.0);
+
+        // Two points with the same x make a step.
+        let step = Curve::new(vec![(0.0, 0.0), (0.5, 0.0), (0.5, 1.0), (1.0, 1.0)]).unwrap();
+        assert_eq!(step.y(0.49), 0.0);
+        assert_eq!(step.y(0.5), 1.0);
+
+        let single = Curve::new(vec![(0.3, 0.6)]).unwrap();
+        assert_eq!((single.y(0.0), single.y(1.0)), (0.6, 0.6));
+    }
+
+    #[test]
+    fn converts_into_animation_curve() {
+        let curve = Curve::new(vec![(0.0, 0.0), (1.0, 1.0)]).unwrap();
+        assert_eq!(
+            AnimationCurve::from(curve.clone()),
+            AnimationCurve::PiecewiseLinear(curve)
+        );
+    }
+
+    #[test]
+    fn invalid_points() {
+        assert!(validate_points(&[]).is_err());
+        assert!(validate_points(&[(0.0, 0.0), (1.5, 1.0)]).is_err());
+        assert!(validate_points(&[(0.6, 0.0), (0.4, 1.0)]).is_err());
+        for points in [vec![], vec![(-0.1, 0.0)], vec![(0.6, 0.0), (0.4, 1.0)]] {
+            assert_eq!(Curve::new(points), Err(AnimationError::InvalidPoints));
+        }
+
+        let curve = Curve::new(vec![(0.0, 0.0), (1.0, f64::NAN)]).unwrap();
+        assert_eq!(curve.validate(), Err(AnimationError::NonFiniteParameter));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_validates_points() {
+        let curve: Curve = serde_json::from_str(r#"{"points": [[0.0, 0.0], [1.0, 1.0]]}"#).unwrap();
+        assert_eq!(curve.points(), [(0.0, 0.0), (1.0, 1.0)]);
+        assert!(serde_json::from_str::<Curve>(r#"{"points": []}"#).is_err());
+        assert!(serde_json::from_str::<Curve>(r#"{"points": [[0.9, 0.0], [0.1, 1.0]]}"#).is_err());
+    }
+}
@@ -4,21 +4,54 @@ use std::time::Duration;
 #[cfg(feature = "serde")]
 use serde::{de::Visitor, Deserialize, Serialize};
 
-use super::AnimationCurve;
+use super::{cubic, AnimationCurve};
+use crate::AnimationError;
 
 /// Delta by which we check durations and values.
 const DELTA: f64 = 0.001;
 
+/// The default epsilon of spring curves, see [`Precision::Normal`]
+pub const DEFAULT_EPSILON: f64 = 0.0001;
+
+/// Named presets for the epsilon of a spring curve.
+///
+/// The epsilon determines how close to the target the spring must be before it's considered at
+/// rest. Lower precisions make springs settle faster, giving shorter durations.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Precision {
+    /// Epsilon of `0.001`, for faster-settling springs.
+    Coarse,
+    /// Epsilon of `0.0001`, this is the default.
+    #[default]
+    Normal,
+    /// Epsilon of `0.00001`, for very large animated values.
+    Fine,
+}
+
+impl Precision {
+    /// Get the epsilon value of this precision.
+    pub fn epsilon(&self) -> f64 {
+        match self {
+            Self::Coarse => 0.001,
+            Self::Normal => DEFAULT_EPSILON,
+            Self::Fine => 0.00001,
+        }
+    }
+}
+
 /// Spring-based curve.
 ///
 /// Implementation converted from the following:
 /// - https://github.com/GNOME/libadwaita/blob/main/src/adw-spring-animation.c
 /// - https://github.com/GNOME/libadwaita/blob/main/src/adw-spring-params.c
-#[derive(Debug, Clone, Copy)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Curve {
     initial_velocity: f64,
+    // The normalized position the spring starts from, only non-zero when seeded from an in-flight
+    // animation, so it's not part of the configuration.
+    initial_position: f64,
     clamp: bool,
     // spring parameters
     mass: f64,
@@ -27,6 +60,9 @@ pub struct Curve {
     epsilon: f64, /* this is also called precision in places like react spring
                    * unless you are really nitty gritty about your animations you wont touch
                    * this */
+    // If set, integrate the spring numerically with this fixed time step (in seconds), instead of
+    // using the analytic solution.
+    integration_step: Option<f64>,
 }
 
 impl Into<AnimationCurve> for Curve {
@@ -35,6 +71,39 @@ impl Into<AnimationCurve> for Curve {
     }
 }
 
+// Custom serializer to write the damping ratio, like the deserializer reads, instead of the
+// computed damping.
+#[cfg(feature = "serde")]
+impl Serialize for Curve {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let seeded = self.initial_position != 0.0;
+        let len = 6 + usize::from(seeded) + usize::from(self.integration_step.is_some());
+        let mut state = serializer.serialize_struct("Curve", len)?;
+        state.serialize_field("initial-velocity", &self.initial_velocity)?;
+        if seeded {
+            state.serialize_field("initial-position", &self.initial_position)?;
+        } else {
+            state.skip_field("initial-position")?;
+        }
+        state.serialize_field("clamp", &self.clamp)?;
+        state.serialize_field("mass", &self.mass)?;
+        state.serialize_field("damping-ratio", &self.damping_ratio())?;
+        state.serialize_field("stiffness", &self.stiffness)?;
+        state.serialize_field("epsilon", &self.epsilon)?;
+        if let Some(dt) = self.integration_step {
+            state.serialize_field("integration-step", &dt)?;
+        } else {
+            state.skip_field("integration-step")?;
+        }
+        state.end()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Curve {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -45,11 +114,13 @@ impl<'de> Deserialize<'de> for Curve {
         #[serde(field_identifier, rename_all = "kebab-case")]
         enum Field {
             InitialVelocity,
+            InitialPosition,
             Clamp,
             Mass,
             DampingRatio,
             Stiffness,
             Epsilon,
+            IntegrationStep,
         }
 
         struct AnimationVisitor;
@@ -64,11 +135,13 @@ impl<'de> Deserialize<'de> for Curve {
                 A: serde::de::MapAccess<'de>,
             {
                 let mut initial_velocity = None;
+                let mut initial_position = None;
                 let mut clamp = None;
                 let mut mass = None;
                 let mut damping_ratio = None;
                 let mut stiffness = None;
                 let mut epsilon = None;
+                let mut integration_step = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -78,6 +151,12 @@ impl<'de> Deserialize<'de> for Curve {
                             }
                             initial_velocity = Some(map.next_value()?);
                         }
+                        Field::InitialPosition => {
+                            if initial_position.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initial-position"));
+                            }
+                            initial_position = Some(map.next_value()?);
+                        }
                         Field::Clamp => {
                             if clamp.is_some() {
                                 return Err(serde::de::Error::duplicate_field("clamp"));
@@ -108,6 +187,12 @@ impl<'de> Deserialize<'de> for Curve {
                             }
                             epsilon = Some(map.next_value()?);
                         }
+                        Field::IntegrationStep => {
+                            if integration_step.is_some() {
+                                return Err(serde::de::Error::duplicate_field("integration-step"));
+                            }
+                            integration_step = Some(map.next_value()?);
+                        }
                     }
                 }
 
@@ -120,24 +205,41 @@ impl<'de> Deserialize<'de> for Curve {
                 let stiffness =
                     stiffness.ok_or_else(|| serde::de::Error::missing_field("stiffness"))?;
 
-                Ok(Curve::new(
+                let mut curve = Curve::new(
                     initial_velocity,
                     clamp,
                     mass,
                     damping_ratio,
                     stiffness,
                     epsilon,
-                ))
+                );
+                if let Some(position) = initial_position {
+                    curve = curve.with_initial_position(position);
+                }
+                if let Some(dt) = integration_step {
+                    // An invalid step would make the integration loop forever.
+                    if !(dt > 0.0 && f64::is_finite(dt)) {
+                        return Err(serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Float(dt),
+                            &"a positive and finite integration step",
+                        ));
+                    }
+                    curve = curve.with_numeric_integration(dt);
+                }
+
+                Ok(curve)
             }
         }
 
         const FIELDS: &[&str] = &[
             "initial_velocity",
+            "initial-position",
             "clamp",
             "mass",
             "damping-ratio",
             "stiffness",
             "epsilon",
+            "integration-step",
         ];
         deserializer.deserialize_struct("Animation", FIELDS, AnimationVisitor)
     }
@@ -153,7 +255,7 @@ impl Curve {
         stiffness: f64,
         epsilon: Option<f64>,
     ) -> Self {
-        let epsilon = epsilon.unwrap_or(0.0001);
+        let epsilon = epsilon.unwrap_or(DEFAULT_EPSILON);
         // Calculate our damping based on the damping ratio.
         // Thats how libadwaita does it
         let critical_damping = 2.0 * f64::from(mass * stiffness).sqrt();
@@ -161,14 +263,77 @@ impl Curve {
 
         Self {
             initial_velocity,
+            initial_position: 0.0,
             clamp,
             mass,
             damping,
             stiffness,
             epsilon,
+            integration_step: None,
         }
     }
 
+    /// Create a spring that settles in `duration` with the given damping ratio.
+    ///
+    /// This solves for the spring stiffness, with a mass of `1.0`, so that [`Curve::duration`]
+    /// matches the requested duration. See [`Curve::with_damping_ratio`] for the effect of the
+    /// damping ratio.
+    ///
+    /// NOTE: Springs with a damping ratio of `0.0` or below never settle, and no spring settles
+    /// instantly, so `duration` is ignored for them, as well as when it's zero. You then get a
+    /// spring with a stiffness of `1.0`.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::SpringCurve;
+    /// let spring = SpringCurve::from_settling(Duration::from_millis(400), 0.6);
+    /// let error = spring.duration().as_secs_f64() - 0.4;
+    /// assert!(error.abs() < 0.001);
+    /// ```
+    pub fn from_settling(duration: Duration, damping_ratio: f64) -> Self {
+        let unit = Self::new(0.0, false, 1.0, damping_ratio, 1.0, None);
+        let unit_duration = unit.duration();
+        if unit_duration == Duration::MAX || duration.is_zero() {
+            return unit;
+        }
+
+        // Without initial velocity, the spring motion only depends on time through
+        // `sqrt(stiffness / mass)`, so the duration scales with `1 / sqrt(stiffness)`.
+        let target = duration.as_secs_f64();
+        let mut stiffness = (unit_duration.as_secs_f64() / target).powi(2);
+        let mut spring = Self::new(0.0, false, 1.0, damping_ratio, stiffness, None);
+        // The root finding of overdamped springs doesn't scale exactly, so refine the estimate.
+        for _ in 0..16 {
+            let actual = spring.duration().as_secs_f64();
+            if (actual - target).abs() <= DELTA / 10.0 || actual <= 0.0 {
+                break;
+            }
+
+            stiffness *= (actual / target).powi(2);
+            spring = Self::new(0.0, false, 1.0, damping_ratio, stiffness, None);
+        }
+
+        spring
+    }
+
+    /// Change the spring initial velocity in place.
+    ///
+    /// The velocity is expressed in normalized units per second, where `1.0` is the full distance
+    /// of the animation.
+    pub fn with_initial_velocity(mut self, initial_velocity: f64) -> Self {
+        self.initial_velocity = initial_velocity;
+        self
+    }
+
+    /// Change the spring initial position in place.
+    ///
+    /// The position is normalized, the spring goes from `initial_position` and settles at `1.0`.
+    /// This is useful to seed a spring from an in-flight animation.
+    pub fn with_initial_position(mut self, initial_position: f64) -> Self {
+        self.initial_position = initial_position;
+        self
+    }
+
     /// Change whether the spring clamps in place.
     ///
     /// Clamping the spring will make it not overshoot, but instead stopping at when it reaches the
@@ -204,6 +369,14 @@ impl Curve {
         self
     }
 
+    /// Get the spring damping ratio.
+    ///
+    /// See [`Curve::with_damping_ratio`]
+    pub fn damping_ratio(&self) -> f64 {
+        let critical_damping = 2.0 * (self.mass * self.stiffness).sqrt();
+        self.damping / critical_damping
+    }
+
     /// Change the spring stiffness in place.
     ///
     /// The stiffness affects how strong the force exerted by the spring is.
@@ -221,8 +394,50 @@ impl Curve {
         self
     }
 
+    /// Change the spring's epsilon in place, using a named precision.
+    ///
+    /// See [`Curve::with_epsilon`]
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.epsilon = precision.epsilon();
+        self
+    }
+
+    /// Integrate the spring numerically with a fixed time step `dt` (in seconds).
+    ///
+    /// By default, the spring uses the analytic solution of its differential equation. With this
+    /// mode, the spring is instead simulated using semi-implicit Euler integration, like most game
+    /// physics engines do. Smaller steps are more precise, but more expensive to compute.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `dt` is not strictly positive and finite.
+    pub fn with_numeric_integration(mut self, dt: f64) -> Self {
+        assert!(
+            dt > 0.0 && dt.is_finite(),
+            "the integration step must be positive and finite, got {dt}"
+        );
+        self.integration_step = Some(dt);
+        self
+    }
+
+    /// Use the analytic solution of the spring differential equation.
+    ///
+    /// This is the default.
+    pub fn with_analytic_solution(mut self) -> Self {
+        self.integration_step = None;
+        self
+    }
+
     /// Get the duration of this spring animation.
     pub fn duration(&self) -> Duration {
+        // An invalid spring can't be solved, consider that it never ends.
+        if self.validate().is_err() {
+            return Duration::MAX;
+        }
+        if let Some(dt) = self.integration_step {
+            return self.integrated_duration(dt);
+        }
+
         let beta = self.damping / (2.0 * self.mass);
 
         // Spring never ends, too bad
@@ -284,7 +499,11 @@ impl Curve {
 
         // A difference from libadwaita is that we don't check if the start and end are greater
         // than f64::EPSILON since they are constant (0.0 and 1.0 respectively)
-        while (1.0 - y > self.epsilon) || (y - 1.0 > self.epsilon) {
+        //
+        // The spring can jump past the end between two steps, so we stop as soon as we reached
+        // it, from whichever side we started.
+        let from_below = self.initial_position <= 1.0;
+        while (from_below && 1.0 - y > self.epsilon) || (!from_below && y - 1.0 > self.epsilon) {
             if x > 200.0
             /* 20000 (max iters) * 0.001 (1ms) */
             {
@@ -299,11 +518,79 @@ impl Curve {
         Duration::from_secs_f64(x)
     }
 
+    /// Check whether this spring has the same configuration as `other`.
+    ///
+    /// The initial position is ignored, since it's only set when seeding from an in-flight state.
+    pub(crate) fn config_eq(&self, other: &Self) -> bool {
+        self.initial_velocity == other.initial_velocity
+            && self.clamp == other.clamp
+            && self.mass == other.mass
+            && self.damping == other.damping
+            && self.stiffness == other.stiffness
+            && self.epsilon == other.epsilon
+            && self.integration_step == other.integration_step
+    }
+
+    /// Check that the spring parameters are finite, and let the spring settle.
+    pub(crate) fn validate(&self) -> Result<(), AnimationError> {
+        let parameters = [
+            self.initial_velocity,
+            self.initial_position,
+            self.mass,
+            self.damping,
+            self.stiffness,
+            self.epsilon,
+        ];
+        if parameters.iter().any(|parameter| !parameter.is_finite())
+            || self.integration_step.is_some_and(|dt| !dt.is_finite())
+        {
+            return Err(AnimationError::NonFiniteParameter);
+        }
+        // Without a positive damping, the spring oscillates forever (or explodes).
+        if self.mass <= 0.0
+            || self.stiffness <= 0.0
+            || self.damping <= 0.0
+            || self.epsilon <= 0.0
+            || self.integration_step.is_some_and(|dt| dt <= 0.0)
+        {
+            return Err(AnimationError::DivergentSpring);
+        }
+
+        Ok(())
+    }
+
     /// Get a value inside for a `t` seconds passed since the spring started
     pub fn oscillate(&self, t: f64) -> f64 {
-        let v0 = self.initial_velocity;
-        let x0 = -1.0; // x0 is start - end, but start is always 0.0, soo.
+        self.oscillate_from(t, self.initial_position, self.initial_velocity)
+    }
+
+    /// Get a value for `t` seconds passed since the spring started from an arbitrary state.
+    ///
+    /// `start` is the normalized position the spring starts from, and `initial_velocity` its
+    /// velocity at that point. The spring always settles at `1.0`. This is useful to seed the
+    /// spring from an in-flight state, for example when retargeting an animation.
+    ///
+    /// If the spring clamps, the value stops at `1.0` once it reaches it, instead of overshooting.
+    pub fn oscillate_from(&self, t: f64, start: f64, initial_velocity: f64) -> f64 {
+        let value = self.solve(t, start, initial_velocity);
+        if !self.clamp {
+            value
+        } else if start <= 1.0 {
+            value.min(1.0)
+        } else {
+            value.max(1.0)
+        }
+    }
+
+    /// Solve the spring equation for `t` seconds passed since the spring started from `start`.
+    fn solve(&self, t: f64, start: f64, initial_velocity: f64) -> f64 {
+        if let Some(dt) = self.integration_step {
+            return self.integrate(t, dt, start, initial_velocity);
+        }
+
+        let v0 = initial_velocity;
         let end = 1.0;
+        let x0 = start - end;
 
         let beta = self.damping / (2.0 * self.mass);
         let omega0 = (self.stiffness / self.mass).sqrt();
@@ -322,7 +609,7 @@ impl Curve {
             // Second possibility: animation is underdamped.
             let omega1 = (omega0.powf(2.0) - beta.powf(2.0)).sqrt();
             end + envelope
-                * (x0 * (omega1 * t).cos() + ((beta + x0 * v0) / omega1) * (omega1 * t).sin())
+                * (x0 * (omega1 * t).cos() + ((beta * x0 + v0) / omega1) * (omega1 * t).sin())
         } else if beta > omega0 {
             // Third possibility: animation is overmapped.
             let omega2 = (beta.powf(2.0) - omega0.powf(2.0)).sqrt();
@@ -332,4 +619,360 @@ impl Curve {
             unreachable!("Something really wrong happened with spring animations...");
         }
     }
+
+    /// Get a cubic bezier curve approximating this spring.
+    ///
+    /// This is useful to degrade to a cubic curve where springs aren't supported, for example when
+    /// exporting to CSS. The curve is fitted with least-squares over the part of the spring before
+    /// it first reaches its target, mapped to `[0.0, 1.0]`.
+    ///
+    /// NOTE: This is only an approximation, since a cubic curve can't reproduce the bounce of an
+    /// underdamped spring. It's also up to you to set a duration to the animation, for example the
+    /// time it takes for the spring to reach its target.
+    pub fn to_cubic_approximation(&self) -> cubic::Curve {
+        const SAMPLES: usize = 256;
+        let linear = cubic::Curve::new((1.0 / 3.0, 1.0 / 3.0), (2.0 / 3.0, 2.0 / 3.0));
+
+        let duration = self.duration();
+        if duration == Duration::MAX || duration.is_zero() {
+            // Nothing meaningful to fit.
+            return linear;
+        }
+
+        // Only fit the part until the spring first reaches its target, we can't bounce anyway.
+        let total = duration.as_secs_f64();
+        let mut points = Vec::with_capacity(SAMPLES + 1);
+        for i in 0..=SAMPLES {
+            let t = total * i as f64 / SAMPLES as f64;
+            let y = self.oscillate(t);
+            points.push((t, y.min(1.0)));
+            if y >= 1.0 - self.epsilon {
+                break;
+            }
+        }
+        if points.len() < 3 {
+            return linear;
+        }
+
+        // Normalize the points so that the curve ends at (1, 1), like every cubic curve.
+        let end = points[points.len() - 1].0;
+        for point in &mut points {
+            point.0 /= end;
+        }
+        let last = points.len() - 1;
+        points[last] = (1.0, 1.0);
+
+        // Estimate the curve parameter of each point from the distance along the curve.
+        let mut params = vec![0.0; points.len()];
+        for i in 1..points.len() {
+            let (dx, dy) = (points[i].0 - points[i - 1].0, points[i].1 - points[i - 1].1);
+            params[i] = params[i - 1] + dx.hypot(dy);
+        }
+        let length = params[last];
+        for param in &mut params {
+            *param /= length;
+        }
+
+        // B(t) = 3(1-t)²t P1 + 3(1-t)t² P2 + t³, solve the normal equations for P1 and P2.
+        let (mut aa, mut ab, mut bb) = (0.0, 0.0, 0.0);
+        let (mut ax, mut bx, mut ay, mut by) = (0.0, 0.0, 0.0, 0.0);
+        for (&(x, y), &t) in points.iter().zip(&params) {
+            let a = 3.0 * (1.0 - t).powi(2) * t;
+            let b = 3.0 * (1.0 - t) * t.powi(2);
+            let t3 = t.powi(3);
+            aa += a * a;
+            ab += a * b;
+            bb += b * b;
+            ax += a * (x - t3);
+            bx += b * (x - t3);
+            ay += a * (y - t3);
+            by += b * (y - t3);
+        }
+
+        let det = aa * bb - ab * ab;
+        if det.abs() <= f64::EPSILON {
+            return linear;
+        }
+        let solve = |ra: f64, rb: f64| ((ra * bb - rb * ab) / det, (rb * aa - ra * ab) / det);
+        let (x1, x2) = solve(ax, bx);
+        let (y1, y2) = solve(ay, by);
+
+        cubic::Curve::new((x1.clamp(0.0, 1.0), y1), (x2.clamp(0.0, 1.0), y2))
+    }
+
+    /// Get the total mechanical energy of the spring `t` seconds after it started.
+    ///
+    /// This is the sum of the kinetic energy of the mass and the potential energy stored in the
+    /// spring, relative to its rest position at `1.0`. For a damped spring it only decreases over
+    /// time, which makes it a robust measure of whether the spring settled.
+    pub fn energy_at(&self, t: f64) -> f64 {
+        let x = self.oscillate(t);
+        // Central difference, forward differences are not precise enough to keep the energy
+        // decreasing for stiff springs.
+        let v = (self.oscillate(t + DELTA) - self.oscillate(t - DELTA)) / (2.0 * DELTA);
+        0.5 * self.mass * v.powi(2) + 0.5 * self.stiffness * (x - 1.0).powi(2)
+    }
+
+    /// Check whether the spring energy `t` seconds after it started is below `epsilon`.
+    ///
+    /// See [`Curve::energy_at`].
+    pub fn is_at_rest_energy(&self, t: f64, epsilon: f64) -> bool {
+        self.energy_at(t) < epsilon
+    }
+
+    /// Advance the spring state `(position, velocity)` by `dt` seconds using semi-implicit Euler.
+    fn step(&self, (x, v): (f64, f64), dt: f64) -> (f64, f64) {
+        // The spring always goes from 0.0 to 1.0
+        let acceleration = (-self.stiffness * (x - 1.0) - self.damping * v) / self.mass;
+        let v = v + acceleration * dt;
+        (x + v * dt, v)
+    }
+
+    /// Get the value for `t` seconds passed since the spring started from `start`, integrating with
+    /// `dt` steps.
+    fn integrate(&self, t: f64, dt: f64, start: f64, initial_velocity: f64) -> f64 {
+        let mut state = (start, initial_velocity);
+        let mut elapsed = 0.0;
+        while elapsed + dt <= t {
+            state = self.step(state, dt);
+            elapsed += dt;
+        }
+
+        // Account for the leftover time to avoid stuttering between steps.
+        let leftover = t - elapsed;
+        if leftover > 0.0 {
+            state = self.step(state, leftover);
+        }
+
+        state.0
+    }
+
+    /// Get the duration of this spring animation, simulating it with `dt` steps until it's at rest.
+    fn integrated_duration(&self, dt: f64) -> Duration {
+        let mut state = (self.initial_position, self.initial_velocity);
+        let mut elapsed = 0.0;
+        // Like `first_zero`, clamped springs settle once they reach the end from either side.
+        let from_below = self.initial_position <= 1.0;
+
+        loop {
+            let (x, v) = state;
+            let settled = if self.clamp && from_below {
+                x >= 1.0 - self.epsilon
+            } else if self.clamp {
+                x <= 1.0 + self.epsilon
+            } else {
+                (1.0 - x).abs() <= self.epsilon && v.abs() <= self.epsilon
+            };
+            if settled {
+                return Duration::from_secs_f64(elapsed);
+            }
+
+            if elapsed > 200.0 {
+                // Spring never ends, too bad
+                return Duration::MAX;
+            }
+
+            state = self.step(state, dt);
+            elapsed += dt;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_integration_converges_like_analytic() {
+        for damping_ratio in [0.3, 1.0, 1.5] {
+            let analytic = Curve::new(0.0, false, 1.0, damping_ratio, 300.0, None);
+            let numeric = analytic.with_numeric_integration(0.0005);
+
+            for i in 0..=100 {
+                let t = i as f64 / 100.0;
+                let error = (analytic.oscillate(t) - numeric.oscillate(t)).abs();
+                assert!(
+                    error < 0.01,
+                    "t = {t}, ratio = {damping_ratio}, error = {error}"
+                );
+            }
+
+            // Once the numeric spring settled, the analytic one did too.
+            let duration = numeric.duration().as_secs_f64();
+            assert!((numeric.oscillate(duration) - 1.0).abs() <= DEFAULT_EPSILON);
+            assert!((analytic.oscillate(duration) - 1.0).abs() <= DEFAULT_EPSILON);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_integration_step_panics() {
+        let _ = Curve::new(0.0, false, 1.0, 1.0, 300.0, None).with_numeric_integration(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_integration_step_panics() {
+        let _ = Curve::new(0.0, false, 1.0, 1.0, 300.0, None).with_numeric_integration(f64::NAN);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn invalid_integration_step_fails_to_deserialize() {
+        let config = |step: &str| {
+            format!(
+                r#"{{"initial-velocity": 0.0, "clamp": false, "mass": 1.0, "damping-ratio": 1.0,
+                    "stiffness": 300.0, "integration-step": {step}}}"#
+            )
+        };
+        assert!(serde_json::from_str::<Curve>(&config("0.001")).is_ok());
+        assert!(serde_json::from_str::<Curve>(&config("0")).is_err());
+        assert!(serde_json::from_str::<Curve>(&config("-0.001")).is_err());
+    }
+
+    #[test]
+    fn underdamped_matches_the_analytic_solution() {
+        // beta = 5 and omega1 = sqrt(75), reference values from
+        // x(t) = 1 + e^(-beta t) * (x0 cos(omega1 t) + (beta x0 + v0) / omega1 sin(omega1 t))
+        let spring = Curve::new(0.0, false, 1.0, 0.5, 100.0, None);
+        assert!((spring.oscillate(0.1) - 0.3402998466082984).abs() < 1e-12);
+        let value = spring.oscillate_from(0.2, 0.5, 3.0);
+        assert!((value - 1.050496706326956).abs() < 1e-12);
+
+        // The spring starts where and how fast it was told to, which the swapped coefficient in
+        // the sine term broke.
+        let dt = 1e-7;
+        for (start, velocity) in [(0.0, 0.0), (0.5, 3.0), (1.5, -2.0)] {
+            assert!((spring.oscillate_from(0.0, start, velocity) - start).abs() < 1e-12);
+            let slope = (spring.oscillate_from(dt, start, velocity) - start) / dt;
+            assert!((slope - velocity).abs() < 1e-4, "{slope} != {velocity}");
+        }
+    }
+
+    #[test]
+    fn seeded_springs_converge_to_one() {
+        for ratio in [0.3, 1.0, 1.5] {
+            let spring = Curve::new(0.0, false, 1.0, ratio, 300.0, None);
+            for (start, velocity) in [(0.4, 0.0), (-1.0, 5.0), (1.8, -3.0)] {
+                let value = spring.oscillate_from(3.0, start, velocity);
+                assert!(
+                    (value - 1.0).abs() < 1e-4,
+                    "ratio = {ratio}, start = {start}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn coarser_precisions_settle_sooner() {
+        for ratio in [0.3, 1.0, 1.5] {
+            let spring = Curve::new(0.0, false, 1.0, ratio, 300.0, None);
+            let duration = |precision| spring.with_precision(precision).duration();
+            assert_eq!(duration(Precision::Normal), spring.duration());
+            assert!(duration(Precision::Coarse) < duration(Precision::Normal));
+            assert!(duration(Precision::Normal) < duration(Precision::Fine));
+        }
+    }
+
+    #[test]
+    fn energy_decreases_monotonically() {
+        for ratio in [0.2, 0.7, 1.0, 1.5] {
+            let spring = Curve::new(0.0, false, 1.0, ratio, 300.0, None);
+            // All the energy is in the stretched spring at first.
+            assert!((spring.energy_at(0.0) - 150.0).abs() < 1e-3);
+
+            let duration = spring.duration().as_secs_f64();
+            let mut previous = f64::INFINITY;
+            for i in 0..=1000 {
+                let energy = spring.energy_at(duration * i as f64 / 1000.0);
+                assert!(energy <= previous + 1e-9, "ratio = {ratio}, step {i}");
+                previous = energy;
+            }
+            assert!(spring.is_at_rest_energy(duration * 2.0, 1e-6));
+            assert!(!spring.is_at_rest_energy(duration / 10.0, 1e-6));
+        }
+    }
+
+    #[test]
+    fn clamped_underdamped_springs_never_overshoot() {
+        let bouncy = Curve::new(2.0, false, 1.0, 0.2, 400.0, None);
+        let clamped = Curve::new(2.0, true, 1.0, 0.2, 400.0, None);
+        let times = (0..3000).map(|ms| f64::from(ms) / 1000.0);
+        assert!(times.clone().any(|t| bouncy.oscillate(t) > 1.0));
+
+        let numeric = clamped.with_numeric_integration(0.001);
+        for t in times {
+            assert!(clamped.oscillate(t) <= 1.0, "overshot at {t}s");
+            assert!(numeric.oscillate(t) <= 1.0, "overshot at {t}s");
+        }
+    }
+
+    #[test]
+    fn cubic_approximation_endpoints() {
+        for damping_ratio in [0.5, 1.0, 1.5] {
+            let spring = Curve::new(0.0, false, 1.0, damping_ratio, 300.0, None);
+            let cubic = spring.to_cubic_approximation();
+            assert_eq!(cubic.validate(), Ok(()));
+            assert_eq!(cubic.y(0.0), 0.0);
+            assert_eq!(cubic.y(1.0), 1.0);
+            // The fitted curve starts rising from 0 and settles close to 1 at its end.
+            assert!((0.0..cubic.y(0.5)).contains(&cubic.y(0.01)));
+            let gap = 1.0 - cubic.y(0.99);
+            assert!(gap.abs() < 0.05, "{damping_ratio}: {gap}");
+        }
+
+        // A spring that never settles has nothing to fit, so it falls back to a linear curve.
+        let endless = Curve::new(0.0, false, 1.0, 0.0, 300.0, None);
+        let cubic = endless.to_cubic_approximation();
+        assert!((cubic.y(0.5) - 0.5).abs() < 1e-3);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let springs = [
+            Curve::new(0.5, false, 1.0, 0.8, 300.0, None),
+            Curve::new(0.0, true, 2.0, 1.2, 800.0, Some(0.001)).with_numeric_integration(0.002),
+            Curve::new(-1.5, false, 1.0, 0.3, 500.0, None).with_initial_position(0.4),
+        ];
+        for spring in springs {
+            let json = serde_json::to_string(&spring).unwrap();
+            assert!(json.contains("\"damping-ratio\":"), "{json}");
+            let parsed: Curve = serde_json::from_str(&json).unwrap();
+            // The damping is recomputed from the damping ratio, which can round differently.
+            let error = (parsed.damping - spring.damping).abs() / spring.damping;
+            assert!(error < 1e-12, "{parsed:?} != {spring:?}");
+            assert_eq!(
+                Curve {
+                    damping: spring.damping,
+                    ..parsed
+                },
+                spring
+            );
+        }
+    }
+
+    #[test]
+    fn clamped_springs_settle_from_above() {
+        let spring = Curve::new(0.0, true, 1.0, 0.5, 300.0, None).with_initial_position(1.5);
+        let numeric = spring.with_numeric_integration(0.001);
+        let (analytic, integrated) = (spring.duration(), numeric.duration());
+        assert!(!integrated.is_zero());
+        let error = integrated.as_secs_f64() - analytic.as_secs_f64();
+        assert!(error.abs() < 0.01, "{integrated:?} != {analytic:?}");
+        assert!(numeric.oscillate(integrated.as_secs_f64()) <= 1.0 + 1e-3);
+    }
+
+    #[test]
+    fn from_settling_ignores_impossible_durations() {
+        let unit = |damping_ratio| Curve::new(0.0, false, 1.0, damping_ratio, 1.0, None);
+        assert_eq!(Curve::from_settling(Duration::ZERO, 0.6), unit(0.6));
+        assert_eq!(
+            Curve::from_settling(Duration::from_millis(400), 0.0),
+            unit(0.0)
+        );
+
+        let spring = Curve::from_settling(Duration::from_millis(400), 0.6);
+        assert!((spring.duration().as_secs_f64() - 0.4).abs() < 0.001);
+    }
 }
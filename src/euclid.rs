@@ -0,0 +1,84 @@
+//! Animation implementation for [`euclid`] typed geometry.
+//!
+//! All the implementations are generic over the unit marker `U`, and lerp each component.
+
+use euclid::{Box2D, Point2D, Size2D, Vector2D};
+
+use crate::Animable;
+
+impl<T: Animable, U> Animable for Point2D<T, U> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            T::lerp(&start.x, &end.x, progress),
+            T::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl<T: Animable, U> Animable for Vector2D<T, U> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            T::lerp(&start.x, &end.x, progress),
+            T::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl<T: Animable, U> Animable for Size2D<T, U> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            T::lerp(&start.width, &end.width, progress),
+            T::lerp(&start.height, &end.height, progress),
+        )
+    }
+}
+
+impl<T: Animable, U> Animable for Box2D<T, U> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            <Point2D<T, U> as Animable>::lerp(&start.min, &end.min, progress),
+            <Point2D<T, U> as Animable>::lerp(&start.max, &end.max, progress),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use euclid::default;
+
+    use super::*;
+
+    // Point2D and friends have an inherent `lerp` method, so we go through the trait explicitly.
+    fn lerp<T: Animable>(start: T, end: T, progress: f64) -> T {
+        T::lerp(&start, &end, progress)
+    }
+
+    #[test]
+    fn point_vector_and_size() {
+        let point: default::Point2D<f64> =
+            lerp(Point2D::new(0.0, 10.0), Point2D::new(10.0, 30.0), 0.25);
+        assert_eq!(point, Point2D::new(2.5, 15.0));
+
+        let vector: default::Vector2D<f64> =
+            lerp(Vector2D::new(-4.0, 0.0), Vector2D::new(4.0, 8.0), 0.5);
+        assert_eq!(vector, Vector2D::new(0.0, 4.0));
+
+        let size: default::Size2D<f64> =
+            lerp(Size2D::new(100.0, 50.0), Size2D::new(200.0, 0.0), 0.5);
+        assert_eq!(size, Size2D::new(150.0, 25.0));
+
+        // The scalar type is not limited to f64.
+        let point: default::Point2D<f32> =
+            lerp(Point2D::new(0.0, 0.0), Point2D::new(2.0, 4.0), 0.5);
+        assert_eq!(point, Point2D::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn box_lerps_both_corners() {
+        let start = default::Box2D::new(Point2D::new(0.0, 0.0), Point2D::new(10.0, 10.0));
+        let end = default::Box2D::new(Point2D::new(20.0, 40.0), Point2D::new(40.0, 80.0));
+        let middle = lerp(start, end, 0.5);
+        assert_eq!(middle.min, Point2D::new(10.0, 20.0));
+        assert_eq!(middle.max, Point2D::new(25.0, 45.0));
+    }
+}
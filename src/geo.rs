@@ -0,0 +1,45 @@
+//! Animation implementation for [`geo`] coordinates.
+//!
+//! NOTE: Coordinates are interpolated linearly on the plane, not along the great circle between
+//! them. This is fine for short distances, but long ones will not follow the shortest path on the
+//! globe.
+
+use geo::{Coord, Point};
+
+use crate::Animable;
+
+impl Animable for Coord<f64> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self {
+            x: f64::lerp(&start.x, &end.x, progress),
+            y: f64::lerp(&start.y, &end.y, progress),
+        }
+    }
+}
+
+impl Animable for Point<f64> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self(Coord::lerp(&start.0, &end.0, progress))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coord_lerps_on_the_plane() {
+        let start = Coord { x: 2.0, y: 48.5 };
+        let end = Coord { x: -1.0, y: 51.5 };
+        let value = <Coord<f64> as Animable>::lerp(&start, &end, 0.5);
+        assert_eq!(value, Coord { x: 0.5, y: 50.0 });
+        assert_eq!(<Coord<f64> as Animable>::lerp(&start, &end, 1.0), end);
+    }
+
+    #[test]
+    fn point_lerps_its_coord() {
+        let (start, end) = (Point::new(0.0, 10.0), Point::new(10.0, -10.0));
+        let value = <Point<f64> as Animable>::lerp(&start, &end, 0.25);
+        assert_eq!(value, Point::new(2.5, 5.0));
+    }
+}
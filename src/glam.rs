@@ -0,0 +1,68 @@
+//! Animation implementation for [`glam`] math types.
+
+use glam::{Quat, Vec2, Vec3, Vec4};
+
+use crate::Animable;
+
+impl Animable for Vec2 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.lerp(*end, progress as f32)
+    }
+}
+
+impl Animable for Vec3 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.lerp(*end, progress as f32)
+    }
+}
+
+impl Animable for Vec4 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.lerp(*end, progress as f32)
+    }
+}
+
+/// Rotations are spherically interpolated, see [`Animable`].
+impl Animable for Quat {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start.slerp(*end, progress as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec3_lerps_components() {
+        let value = <Vec3 as Animable>::lerp(
+            &Vec3::new(0.0, 10.0, -4.0),
+            &Vec3::new(10.0, 0.0, 4.0),
+            0.25,
+        );
+        assert_eq!(value, Vec3::new(2.5, 7.5, -2.0));
+    }
+
+    #[test]
+    fn quat_slerps() {
+        let start = Quat::IDENTITY;
+        let end = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let middle = <Quat as Animable>::lerp(&start, &end, 0.5);
+        // Spherical interpolation keeps a unit quaternion at a constant angular velocity.
+        assert!(middle.is_normalized());
+        assert!(middle.abs_diff_eq(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4), 1e-6));
+        assert!(<Quat as Animable>::lerp(&start, &end, 1.0).abs_diff_eq(end, 1e-6));
+    }
+
+    #[test]
+    fn quat_extrapolates_along_the_arc() {
+        let start = Quat::IDENTITY;
+        let end = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let past = <Quat as Animable>::lerp(&start, &end, 1.5);
+        let expected = Quat::from_rotation_z(3.0 * std::f32::consts::FRAC_PI_4);
+        assert!(past.abs_diff_eq(expected, 1e-5), "{past:?}");
+        let before = <Quat as Animable>::lerp(&start, &end, -0.5);
+        let expected = Quat::from_rotation_z(-std::f32::consts::FRAC_PI_4);
+        assert!(before.abs_diff_eq(expected, 1e-5), "{before:?}");
+    }
+}
@@ -0,0 +1,197 @@
+//! A scheduler for animations that depend on each other.
+//!
+//! Each animation in an [`AnimationGraph`] is identified by a name, and can be set to start only
+//! after some other animations from the graph finished. The whole graph is then driven by a
+//! single [`AnimationGraph::tick`] call.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use fht_animation::graph::AnimationGraph;
+//! use fht_animation::Animation;
+//!
+//! let mut graph = AnimationGraph::new();
+//! let animation = Animation::new(0.0, 1.0, Duration::from_millis(200));
+//! graph.insert("a", animation.clone(), &[]);
+//! graph.insert("b", animation.clone(), &["a"]);
+//! graph.insert("c", animation.clone(), &["a"]);
+//! graph.insert("d", animation, &["b", "c"]);
+//!
+//! let now = fht_animation::get_monotonic_time();
+//! graph.tick(now);
+//! assert!(graph.is_started("a"));
+//! assert!(!graph.is_started("b") && !graph.is_started("c"));
+//!
+//! // Once "a" finished, both "b" and "c" start, but "d" waits for both of them.
+//! graph.tick(now + Duration::from_millis(200));
+//! assert!(graph.is_started("b") && graph.is_started("c"));
+//! assert!(!graph.is_started("d"));
+//! ```
+
+use std::time::Duration;
+
+use crate::{Animable, Animation};
+
+struct Node<T: Animable> {
+    name: String,
+    animation: Animation<T>,
+    // Indices of the nodes that must finish before this one starts.
+    // They are always inserted before this node, which keeps the graph acyclic.
+    dependencies: Vec<usize>,
+    started: bool,
+}
+
+/// A collection of named animations, where each animation can start after others finished.
+pub struct AnimationGraph<T: Animable> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: Animable> Default for AnimationGraph<T> {
+    fn default() -> Self {
+        Self { nodes: vec![] }
+    }
+}
+
+impl<T: Animable> AnimationGraph<T> {
+    /// Create a new empty animation graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a new animation in the graph, starting after all the `after` animations finished.
+    ///
+    /// If `after` is empty, the animation will start on the next [`AnimationGraph::tick`].
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an animation from `after` was not inserted before, or if an
+    /// animation named `name` already exists in the graph.
+    pub fn insert(&mut self, name: impl Into<String>, animation: Animation<T>, after: &[&str]) {
+        let name = name.into();
+        assert!(
+            self.index_of(&name).is_none(),
+            "animation {name:?} already exists in the graph"
+        );
+
+        let dependencies = after
+            .iter()
+            .map(|dependency| {
+                self.index_of(dependency).unwrap_or_else(|| {
+                    panic!("animation {dependency:?} must be inserted before {name:?}")
+                })
+            })
+            .collect();
+
+        self.nodes.push(Node {
+            name,
+            animation,
+            dependencies,
+            started: false,
+        });
+    }
+
+    /// Tick all the running animations of the graph, and start the ones whose dependencies
+    /// finished.
+    pub fn tick(&mut self, now: Duration) {
+        // Since dependencies are always inserted before the nodes that depend on them, going
+        // through the nodes in order is enough to start all of them in a single pass.
+        for idx in 0..self.nodes.len() {
+            if !self.nodes[idx].started {
+                let ready = self.nodes[idx].dependencies.iter().all(|&dependency| {
+                    let dependency = &self.nodes[dependency];
+                    dependency.started && dependency.animation.is_finished()
+                });
+                if !ready {
+                    continue;
+                }
+
+                let node = &mut self.nodes[idx];
+                node.started = true;
+                node.animation.restart_at(now);
+            }
+
+            self.nodes[idx].animation.tick(now);
+        }
+    }
+
+    /// Get the animation named `name`.
+    pub fn get(&self, name: &str) -> Option<&Animation<T>> {
+        self.index_of(name).map(|idx| &self.nodes[idx].animation)
+    }
+
+    /// Get a mutable reference to the animation named `name`.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Animation<T>> {
+        self.index_of(name)
+            .map(|idx| &mut self.nodes[idx].animation)
+    }
+
+    /// Get the current value of the animation named `name`.
+    pub fn value(&self, name: &str) -> Option<&T> {
+        self.get(name).map(Animation::value)
+    }
+
+    /// Check whether the animation named `name` started.
+    pub fn is_started(&self, name: &str) -> bool {
+        self.index_of(name)
+            .is_some_and(|idx| self.nodes[idx].started)
+    }
+
+    /// Check whether all the animations of the graph are finished.
+    pub fn is_finished(&self) -> bool {
+        self.nodes
+            .iter()
+            .all(|node| node.started && node.animation.is_finished())
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn animation(millis: u64) -> Animation<f64> {
+        Animation::headless(0.0, 1.0, Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn diamond_dependency() {
+        let mut graph = AnimationGraph::new();
+        graph.insert("a", animation(100), &[]);
+        graph.insert("b", animation(100), &["a"]);
+        graph.insert("c", animation(200), &["a"]);
+        graph.insert("d", animation(100), &["b", "c"]);
+        let started =
+            |graph: &AnimationGraph<f64>| ["a", "b", "c", "d"].map(|name| graph.is_started(name));
+
+        graph.tick(Duration::ZERO);
+        assert_eq!(started(&graph), [true, false, false, false]);
+        graph.tick(Duration::from_millis(50));
+        assert_eq!(graph.value("a"), Some(&0.5));
+        assert_eq!(started(&graph), [true, false, false, false]);
+
+        // "b" and "c" start on the tick "a" finishes on.
+        graph.tick(Duration::from_millis(100));
+        assert_eq!(started(&graph), [true, true, true, false]);
+        assert_eq!(graph.value("b"), Some(&0.0));
+        graph.tick(Duration::from_millis(150));
+        assert_eq!(graph.value("b"), Some(&0.5));
+        assert_eq!(graph.value("c"), Some(&0.25));
+
+        // "d" waits for the slowest of its dependencies.
+        graph.tick(Duration::from_millis(200));
+        assert!(graph.get("b").unwrap().is_finished());
+        assert_eq!(started(&graph), [true, true, true, false]);
+        graph.tick(Duration::from_millis(300));
+        assert_eq!(started(&graph), [true, true, true, true]);
+        assert_eq!(graph.value("d"), Some(&0.0));
+        graph.tick(Duration::from_millis(350));
+        assert_eq!(graph.value("d"), Some(&0.5));
+        assert!(!graph.is_finished());
+
+        graph.tick(Duration::from_millis(400));
+        assert!(graph.is_finished());
+    }
+}
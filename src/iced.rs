@@ -35,6 +35,52 @@
 //!     }
 //! }
 //! ```
+//!
+//! If you are animating multiple properties at once, a single widget can drive all of them using
+//! [`AnimationWidget::with_extra`]. You then have to forward each event to all your animations.
+//!
+//! ```rust
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::UpdateValues(event) => {
+//!                 self.position.update(event);
+//!                 self.opacity.update(event);
+//!             }
+//!             // ...
+//!         }
+//!     }
+//!
+//!     fn view(&self) -> iced::Element<Message> {
+//!         AnimationWidget::new(&self.position, text("Hello!"))
+//!             .with_extra(&self.opacity)
+//!             .on_update(Message::UpdateValues)
+//!             .into()
+//!     }
+//! }
+//! ```
+//!
+//! Instead of the widget, you can also drive your animations from a [`frames`] subscription,
+//! ticking them exactly once per presented frame.
+//!
+//! ```rust
+//! impl State {
+//!     fn update(&mut self, message: Message) {
+//!         match message {
+//!             Message::UpdateValue(event) => self.value.update(event),
+//!             // ...
+//!         }
+//!     }
+//!
+//!     fn subscription(&self) -> iced::Subscription<Message> {
+//!         if self.value.is_finished() {
+//!             iced::Subscription::none()
+//!         } else {
+//!             fht_animation::iced::frames().map(Message::UpdateValue)
+//!         }
+//!     }
+//! }
+//! ```
 
 use std::time::Instant;
 
@@ -53,6 +99,11 @@ impl Animable for iced::Vector {
             y: f32::lerp(&start.y, &end.y, progress),
         }
     }
+
+    fn distance(start: &Self, end: &Self) -> Option<f64> {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        Some(f64::from(dx).hypot(f64::from(dy)))
+    }
 }
 
 impl Animable for iced::Point {
@@ -62,6 +113,11 @@ impl Animable for iced::Point {
             y: f32::lerp(&start.y, &end.y, progress),
         }
     }
+
+    fn distance(start: &Self, end: &Self) -> Option<f64> {
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        Some(f64::from(dx).hypot(f64::from(dy)))
+    }
 }
 
 impl Animable for iced::Size {
@@ -134,7 +190,11 @@ impl Animable for iced::Rectangle {
 }
 
 /// An animation update event.
+///
+/// This enum is non-exhaustive, new events can be added in minor versions, so your matches need a
+/// catch-all arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum AnimationEvent {
     /// An animation tick.
     Tick(Instant),
@@ -146,6 +206,15 @@ pub enum AnimationEvent {
     Finished,
 }
 
+/// Get a subscription emitting an [`AnimationEvent::Tick`] for every frame presented.
+///
+/// This uses [`iced::window::frames`], available since iced 0.12, so that animations are ticked
+/// in sync with the display, without stalling or ticking more than needed. Only subscribe while
+/// your animations are running, to avoid redrawing for nothing.
+pub fn frames() -> iced::Subscription<AnimationEvent> {
+    iced::window::frames().map(AnimationEvent::Tick)
+}
+
 impl<T: Animable> Animation<T> {
     /// Update to an [`AnimationEvent`]
     pub fn update(&mut self, event: AnimationEvent) {
@@ -157,9 +226,23 @@ impl<T: Animable> Animation<T> {
     }
 }
 
+/// An animation observed by an [`AnimationWidget`], regardless of its value type.
+pub trait AnimationHandle {
+    /// Check whether the animation is finished or not.
+    fn is_finished(&self) -> bool;
+}
+
+impl<T: Animable> AnimationHandle for Animation<T> {
+    fn is_finished(&self) -> bool {
+        Animation::is_finished(self)
+    }
+}
+
 /// A widget that helps you animate a value from your state.
 pub struct AnimationWidget<'a, T: Animable, Message, Theme, Renderer> {
     animation: &'a Animation<T>,
+    // Other animations driven by this widget, see `AnimationWidget::with_extra`
+    extra: Vec<&'a dyn AnimationHandle>,
     content: Element<'a, Message, Theme, Renderer>,
     on_update: Option<Box<dyn Fn(AnimationEvent) -> Message>>,
 }
@@ -175,11 +258,22 @@ where
     ) -> Self {
         Self {
             animation,
+            extra: vec![],
             content: content.into(),
             on_update: None,
         }
     }
 
+    /// Drive another animation with this widget.
+    ///
+    /// The widget will keep emitting [`AnimationEvent::Tick`] until all of its animations are
+    /// finished, and only then emit [`AnimationEvent::Finished`]. It is up to **you** to tick all
+    /// of them when handling the events.
+    pub fn with_extra<U: Animable + 'a>(mut self, animation: &'a Animation<U>) -> Self {
+        self.extra.push(animation);
+        self
+    }
+
     pub fn on_update<F>(mut self, on_update: F) -> Self
     where
         F: Fn(AnimationEvent) -> Message + 'static,
@@ -309,7 +403,9 @@ where
             viewport,
         );
 
-        if self.animation.is_finished() {
+        let finished = self.animation.is_finished()
+            && self.extra.iter().all(|animation| animation.is_finished());
+        if finished {
             if let Some(on_update) = &self.on_update {
                 shell.publish(on_update(AnimationEvent::Finished));
             }
@@ -338,3 +434,21 @@ where
         Self::new(animation)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn constant_speed_scales_with_point_distance() {
+        let start = iced::Point::ORIGIN;
+        let near = Animation::headless(start, iced::Point::new(30.0, 40.0), Duration::ZERO)
+            .with_constant_speed(250.0);
+        let far = Animation::headless(start, iced::Point::new(60.0, 80.0), Duration::ZERO)
+            .with_constant_speed(250.0);
+        assert_eq!(near.total_duration(), Duration::from_millis(200));
+        assert_eq!(far.total_duration(), near.total_duration() * 2);
+    }
+}
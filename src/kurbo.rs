@@ -0,0 +1,66 @@
+//! Animation implementation for [`kurbo`] geometry types.
+
+use kurbo::{Point, Rect, Size, Vec2};
+
+use crate::Animable;
+
+impl Animable for Point {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            f64::lerp(&start.x, &end.x, progress),
+            f64::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl Animable for Vec2 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            f64::lerp(&start.x, &end.x, progress),
+            f64::lerp(&start.y, &end.y, progress),
+        )
+    }
+}
+
+impl Animable for Size {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            f64::lerp(&start.width, &end.width, progress),
+            f64::lerp(&start.height, &end.height, progress),
+        )
+    }
+}
+
+impl Animable for Rect {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self::new(
+            f64::lerp(&start.x0, &end.x0, progress),
+            f64::lerp(&start.y0, &end.y0, progress),
+            f64::lerp(&start.x1, &end.x1, progress),
+            f64::lerp(&start.y1, &end.y1, progress),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_lerps_components() {
+        let value =
+            <Point as Animable>::lerp(&Point::new(0.0, 10.0), &Point::new(10.0, -10.0), 0.25);
+        assert_eq!(value, Point::new(2.5, 5.0));
+    }
+
+    #[test]
+    fn rect_lerps_all_edges() {
+        let start = Rect::new(0.0, 0.0, 10.0, 10.0);
+        let end = Rect::new(20.0, -10.0, 40.0, 50.0);
+        assert_eq!(
+            <Rect as Animable>::lerp(&start, &end, 0.5),
+            Rect::new(10.0, -5.0, 25.0, 30.0)
+        );
+        assert_eq!(<Rect as Animable>::lerp(&start, &end, 1.0), end);
+    }
+}
@@ -1,25 +1,97 @@
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "cgmath")]
+mod cgmath;
+pub mod color;
 pub mod curve;
+#[cfg(feature = "euclid")]
+mod euclid;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "glam")]
+mod glam;
+pub mod graph;
 #[cfg(feature = "iced")]
 pub mod iced;
+#[cfg(feature = "kurbo")]
+mod kurbo;
+#[cfg(feature = "ordered-float")]
+mod ordered_float;
+pub mod set;
+#[cfg(feature = "nightly")]
+mod simd;
 
 pub use curve::cubic::Curve as CubicCurve;
 pub use curve::spring::Curve as SpringCurve;
+pub use curve::spring::Precision as SpringPrecision;
 pub use curve::AnimationCurve;
 
+/// Time delta (in seconds) used to estimate the velocity of an [`Animation`].
+const VELOCITY_DELTA: f64 = 0.001;
+
+/// Maximum number of missed frames an [`Animation`] catches up on in a single tick.
+const MAX_CATCH_UP_STEPS: u32 = 1024;
+
+/// Maximum number of completed iterations an [`Animation`] reports in a single tick.
+const MAX_LOOP_CALLBACKS: u32 = 1024;
+
 /// A type that can be animated using [`Animation`]
 ///
 /// This trait is intentionally not derivable, its up to you to actually implement scaling of your
 /// custom struct members accordingly.
 ///
-/// You are also responsible to manage variable overflow, if applicable.
+/// You are also responsible to manage variable overflow, if applicable. Values that are meant to
+/// wrap around, like a hue angle, can use [`Wrapping`](std::num::Wrapping) integers, which take
+/// the shortest path across the wraparound:
+///
+/// ```rust
+/// # use std::num::Wrapping;
+/// # use fht_animation::Animable;
+/// let hue = Wrapping::<u8>::lerp(&Wrapping(250), &Wrapping(6), 0.5);
+/// assert_eq!(hue, Wrapping(0));
+/// ```
+///
+/// Rotations, like the quaternions of the `glam` and `cgmath` features, are spherically
+/// interpolated to keep a constant angular velocity. A progress outside of `[0.0, 1.0]`
+/// extrapolates the rotation along the same arc.
 pub trait Animable: Sized + Clone {
     /// Do a linear interpolation between the start and end of this type with a given `progress`.
     ///
     /// `progress` may overshoot/undershoot out of `[0.0, 1.0]` in the case of spring animations,
     /// for example. It is up to you to handle overflows and edge cases with your types.
     fn lerp(start: &Self, end: &Self, progress: f64) -> Self;
+
+    /// Do a linear interpolation like [`Animable::lerp`], but write the result into `out`.
+    ///
+    /// Override this for types that can be updated in-place, to avoid allocating a new value on
+    /// every tick.
+    fn lerp_into(start: &Self, end: &Self, progress: f64, out: &mut Self) {
+        *out = Self::lerp(start, end, progress);
+    }
+
+    /// Get the `progress` at which [`Animable::lerp`] between `start` and `end` gives `value`.
+    ///
+    /// This is the inverse of [`Animable::lerp`], used for seeking animations by value. Return
+    /// `None` if your type can't be inverted, which is the default.
+    fn inverse_lerp(start: &Self, end: &Self, value: &Self) -> Option<f64> {
+        let _ = (start, end, value);
+        None
+    }
+
+    /// Get the distance between `start` and `end`.
+    ///
+    /// This is used to animate at a constant speed, see [`Animation::with_constant_speed`]. Return
+    /// `None` if your type has no meaningful distance, which is the default.
+    fn distance(start: &Self, end: &Self) -> Option<f64> {
+        let _ = (start, end);
+        None
+    }
 }
 
 // Basic impls for rust numeric types
@@ -29,6 +101,16 @@ macro_rules! rust_builtin_impl {
             fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
                 (((*end - *start) as f64) * progress) as $t + *start
             }
+
+            fn inverse_lerp(start: &Self, end: &Self, value: &Self) -> Option<f64> {
+                let progress = (*value as f64 - *start as f64) / (*end as f64 - *start as f64);
+                // If start == end, any progress works, but we can't tell which one.
+                progress.is_finite().then_some(progress)
+            }
+
+            fn distance(start: &Self, end: &Self) -> Option<f64> {
+                Some((*end as f64 - *start as f64).abs())
+            }
         }
     };
 }
@@ -42,26 +124,239 @@ rust_builtin_impl!(i64);
 rust_builtin_impl!(f32);
 rust_builtin_impl!(f64);
 
+/// 128-bit integers are interpolated in integer space, since `f64` only has 53 bits of precision.
+///
+/// The progress is converted to a 32-bit fixed-point fraction, so the result is precise up to
+/// `|end - start| / 2^32`, regardless of how large the values are. The result saturates at the
+/// type bounds if the progress overshoots/undershoots.
+impl Animable for i128 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        let forward = (*end >= *start) == (progress >= 0.0);
+        let offset = scale_u128(end.abs_diff(*start), progress.abs());
+        if forward {
+            start.saturating_add_unsigned(offset)
+        } else {
+            start.saturating_sub_unsigned(offset)
+        }
+    }
+}
+
+/// See the implementation for `i128`.
+impl Animable for u128 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        let forward = (*end >= *start) == (progress >= 0.0);
+        let offset = scale_u128(end.abs_diff(*start), progress.abs());
+        if forward {
+            start.saturating_add(offset)
+        } else {
+            start.saturating_sub(offset)
+        }
+    }
+}
+
+/// Scale `value` by a positive `factor` in integer space, saturating on overflow.
+fn scale_u128(value: u128, factor: f64) -> u128 {
+    // Split the factor into its whole part, and its fractional part as a 32-bit fixed-point
+    // fraction. Then split the value to multiply by the fraction without overflowing.
+    let whole = factor.trunc() as u128;
+    let fraction = (factor.fract() * (1u64 << 32) as f64).round() as u128;
+    let (high, low) = (value >> 32, value & 0xFFFF_FFFF);
+    let scaled_fraction = high * fraction + ((low * fraction) >> 32);
+    value.saturating_mul(whole).saturating_add(scaled_fraction)
+}
+
+// NonZero integers are interpolated in float space, then clamped to never produce zero, even when
+// the progress undershoots.
+macro_rules! rust_nonzero_impl {
+    (unsigned $nz:ty, $t:ty) => {
+        impl Animable for $nz {
+            fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+                let value = f64::lerp(&(start.get() as f64), &(end.get() as f64), progress);
+                // float to int casts saturate, so we only have to avoid zero.
+                <$nz>::new((value as $t).max(1)).unwrap()
+            }
+        }
+    };
+    (signed $nz:ty, $t:ty) => {
+        impl Animable for $nz {
+            fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+                let value = f64::lerp(&(start.get() as f64), &(end.get() as f64), progress);
+                // float to int casts saturate, so we only have to avoid zero, snapping to the
+                // closest non-zero value with the same sign.
+                let fallback = if value.is_sign_negative() { -1 } else { 1 };
+                <$nz>::new(value as $t).unwrap_or(<$nz>::new(fallback).unwrap())
+            }
+        }
+    };
+}
+
+rust_nonzero_impl!(unsigned std::num::NonZeroU8, u8);
+rust_nonzero_impl!(unsigned std::num::NonZeroU16, u16);
+rust_nonzero_impl!(unsigned std::num::NonZeroU32, u32);
+rust_nonzero_impl!(unsigned std::num::NonZeroU64, u64);
+rust_nonzero_impl!(signed std::num::NonZeroI8, i8);
+rust_nonzero_impl!(signed std::num::NonZeroI16, i16);
+rust_nonzero_impl!(signed std::num::NonZeroI32, i32);
+rust_nonzero_impl!(signed std::num::NonZeroI64, i64);
+
+// Wrapping integers take the shortest path around the wraparound, the difference between the
+// endpoints being reinterpreted as a signed value of the same width.
+macro_rules! rust_wrapping_impl {
+    ($t:ty, $signed:ty) => {
+        impl Animable for std::num::Wrapping<$t> {
+            fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+                let delta = end.0.wrapping_sub(start.0) as $signed;
+                // Truncating the offset to the type width wraps it, like the result.
+                let offset = (delta as f64 * progress) as i128;
+                std::num::Wrapping(start.0.wrapping_add(offset as $t))
+            }
+        }
+    };
+}
+
+rust_wrapping_impl!(u8, i8);
+rust_wrapping_impl!(u16, i16);
+rust_wrapping_impl!(u32, i32);
+rust_wrapping_impl!(u64, i64);
+rust_wrapping_impl!(i8, i8);
+rust_wrapping_impl!(i16, i16);
+rust_wrapping_impl!(i32, i32);
+rust_wrapping_impl!(i64, i64);
+
+/// Half-precision floats are interpolated in `f32` space, then rounded back to `f16`.
+///
+/// NOTE: Since `f16` only has 11 bits of precision, values close to the endpoints will snap to
+/// them (or to their nearest representable neighbour) before the animation actually finishes. The
+/// endpoints themselves are always reached exactly since they are representable in `f32`.
+#[cfg(feature = "half")]
+impl Animable for half::f16 {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        half::f16::from_f32(f32::lerp(&start.to_f32(), &end.to_f32(), progress))
+    }
+}
+
 impl<T: Animable, const N: usize> Animable for [T; N] {
     fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
-        let Ok(ret) = start
+        std::array::from_fn(|i| T::lerp(&start[i], &end[i], progress))
+    }
+
+    fn lerp_into(start: &Self, end: &Self, progress: f64, out: &mut Self) {
+        for ((start, end), out) in start.iter().zip(end).zip(out) {
+            T::lerp_into(start, end, progress, out);
+        }
+    }
+}
+
+/// Elements are interpolated pairwise, like the `[T; N]` implementation.
+///
+/// [`Animation::tick`] updates the vector in-place, so animating large vectors doesn't allocate on
+/// every tick.
+///
+/// NOTE: Both vectors are expected to have the same length. If they don't, the result will have
+/// the length of the shortest one, the extra elements being dropped.
+impl<T: Animable> Animable for Vec<T> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        start
             .iter()
             .zip(end)
             .map(|(start, end)| T::lerp(start, end, progress))
-            .collect::<Vec<_>>()
-            .try_into()
-        else {
-            unreachable!("vector len should match slice from iterator")
-        };
+            .collect()
+    }
+
+    fn lerp_into(start: &Self, end: &Self, progress: f64, out: &mut Self) {
+        let len = start.len().min(end.len());
+        out.truncate(len);
+
+        let filled = out.len();
+        for ((start, end), out) in start.iter().zip(end).zip(out.iter_mut()) {
+            T::lerp_into(start, end, progress, out);
+        }
+        out.extend(
+            start[filled..len]
+                .iter()
+                .zip(&end[filled..len])
+                .map(|(start, end)| T::lerp(start, end, progress)),
+        );
+    }
+}
+
+/// Ranges are interpolated by lerping both of their bounds independently.
+///
+/// NOTE: Nothing keeps the interpolated start below the interpolated end. If the start of one
+/// range is after the end of the other, the animated range will cross itself and be empty for
+/// part of the animation.
+///
+/// ```rust
+/// use fht_animation::Animable;
+///
+/// let range = <std::ops::Range<f64>>::lerp(&(0.0..10.0), &(20.0..40.0), 0.5);
+/// assert_eq!(range, 10.0..25.0);
+/// ```
+impl<T: Animable> Animable for std::ops::Range<T> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        T::lerp(&start.start, &end.start, progress)..T::lerp(&start.end, &end.end, progress)
+    }
+
+    fn lerp_into(start: &Self, end: &Self, progress: f64, out: &mut Self) {
+        T::lerp_into(&start.start, &end.start, progress, &mut out.start);
+        T::lerp_into(&start.end, &end.end, progress, &mut out.end);
+    }
+}
+
+/// Inclusive ranges are interpolated by lerping both of their bounds independently.
+///
+/// The same notes as the [`Range`](std::ops::Range) implementation apply.
+impl<T: Animable> Animable for std::ops::RangeInclusive<T> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        T::lerp(start.start(), end.start(), progress)..=T::lerp(start.end(), end.end(), progress)
+    }
+}
+
+/// Durations are interpolated in seconds, saturating at zero if the progress overshoots.
+impl Animable for Duration {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        let secs = f64::lerp(&start.as_secs_f64(), &end.as_secs_f64(), progress);
+        Duration::try_from_secs_f64(secs.max(0.0)).unwrap_or(Duration::MAX)
+    }
+
+    fn inverse_lerp(start: &Self, end: &Self, value: &Self) -> Option<f64> {
+        f64::inverse_lerp(
+            &start.as_secs_f64(),
+            &end.as_secs_f64(),
+            &value.as_secs_f64(),
+        )
+    }
+
+    fn distance(start: &Self, end: &Self) -> Option<f64> {
+        Some(start.abs_diff(*end).as_secs_f64())
+    }
+}
+
+/// A source of normalized progress, to drive an [`Animation`] with something else than a clock.
+///
+/// This is implemented by [`Animation<f64>`], yielding its eased progress, so that a master
+/// animation can drive many others with different curves, and by closures returning the progress.
+///
+/// See [`Animation::drive_from`]
+pub trait ProgressSource {
+    /// Get the current progress of this source, usually in `[0.0, 1.0]`.
+    fn progress(&self) -> f64;
+}
 
-        ret
+impl<F: Fn() -> f64> ProgressSource for F {
+    fn progress(&self) -> f64 {
+        self()
     }
 }
 
 /// The state of an [`Animation`].
+///
+/// This enum is non-exhaustive, new states can be added in minor versions, so your matches need a
+/// catch-all arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+#[non_exhaustive]
 pub enum AnimationState {
     /// The animation is running.
     Running,
@@ -71,6 +366,12 @@ pub enum AnimationState {
     /// the animation's current value. Instead the animation duration will increase for the time
     /// its paused.
     Paused,
+    /// The animation is finished.
+    ///
+    /// [`Animation::tick`] switches to this state once the animation is over, and it stays in this
+    /// state until it gets restarted. The value stays at `end`, so changing the configuration of a
+    /// finished animation, like its curve or duration, only applies once it gets restarted.
+    Finished,
 }
 
 impl std::ops::Neg for AnimationState {
@@ -79,20 +380,91 @@ impl std::ops::Neg for AnimationState {
         match self {
             Self::Paused => Self::Running,
             Self::Running => Self::Paused,
+            // Toggling doesn't make sense for a finished animation.
+            Self::Finished => Self::Finished,
         }
     }
 }
 
+/// The direction in which an [`Animation`] is moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The value is moving from `start` towards `end`.
+    Forward,
+    /// The value is moving from `end` back towards `start`.
+    Backward,
+    /// The value is not moving.
+    Still,
+}
+
+/// An invalid animation configuration, see [`Animation::validate`] and
+/// [`Animation::try_set_duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AnimationError {
+    /// The animation has a zero duration with a timed curve.
+    ZeroDuration,
+    /// A curve parameter is NaN or infinite.
+    NonFiniteParameter,
+    /// A cubic control point has its X coordinate outside of `[0.0, 1.0]`.
+    ControlPointOutOfRange,
+    /// The spring parameters don't let it settle, like a negative damping or a zero mass.
+    DivergentSpring,
+    /// The animation duration can't be set, since the curve derives its own, like springs.
+    CurveMismatch,
+    /// The piecewise curve points are empty, not sorted by X, or have an X outside of `[0.0, 1.0]`.
+    InvalidPoints,
+}
+
+impl std::fmt::Display for AnimationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::ZeroDuration => "timed animation curves require a non-zero duration",
+            Self::NonFiniteParameter => "curve parameters must be finite",
+            Self::ControlPointOutOfRange => "cubic control points must have x in [0.0, 1.0]",
+            Self::DivergentSpring => "spring parameters don't let the spring settle",
+            Self::CurveMismatch => "the animation curve derives its own duration",
+            Self::InvalidPoints => "piecewise curve points must be sorted with x in [0.0, 1.0]",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+/// A callback stored inside an [`Animation`].
+///
+/// Cloning an animation shares its callbacks with the clone.
+#[derive(Clone)]
+struct Callback<A>(Arc<Mutex<dyn FnMut(A) + Send>>);
+
+impl<A> Callback<A> {
+    fn new(f: impl FnMut(A) + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(f)))
+    }
+
+    fn call(&self, arg: A) {
+        // If the callback panicked before, we don't care, just keep going.
+        let mut f = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        (*f)(arg)
+    }
+}
+
+/// The `min`, `max`, and clamping function of an [`Animation`] bounds.
+type Bounds<T> = (T, T, fn(T, &T, &T) -> T);
+
 /// An animatable variable, with a `start` and `end`.
 ///
 /// This struct by itself does nothing, you should be calling [`Animation::tick`] on every frame
 /// that you are using the animation with in order to update [`Animation::value`].
 ///
 /// See [`Animatable`]
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Animation<T: Animable> {
     pub start: T,
     pub end: T,
+    // Diagnostic name, only used for debugging purposes.
+    name: Option<Cow<'static, str>>,
     // We update the current value when we call [`Animation::tick`] so that calling
     // [`Animation::current_value`] is very very cheap
     current_value: T,
@@ -100,45 +472,431 @@ pub struct Animation<T: Animable> {
     // State and curve
     state: AnimationState,
     curve: AnimationCurve,
+    // If set, the animation only updates this many times per second.
+    fps_quantization: Option<u32>,
+    // If set, the curve progress is clamped to [-max_overshoot, 1.0 + max_overshoot]
+    max_overshoot: Option<f64>,
+    // Whether the curve extrapolates past the start and end of the timeline.
+    unclamped: bool,
+    // The last elapsed time (in seconds) the curve got evaluated at, and the resulting progress.
+    progress_cache: Option<(f64, f64)>,
+    // Value-space bounds applied after lerp, alongside the function to clamp with them.
+    // We store the function since we can't require `T: PartialOrd` on every animation.
+    bounds: Option<Bounds<T>>,
 
     // Animaton timing
     // started_at and last_tick = durations since unix epoch
     started_at: Duration,
     last_tick: Duration,
+    // How far ahead of the caller clock the animation clock is. Seeking can need `started_at` to go
+    // before the clock origin, which a `Duration` can't represent, so we shift the clock instead.
+    clock_offset: Duration,
     duration: Duration,
+    // Time to wait before playing the animation, `started_at` gets pushed forward by it.
+    delay: Duration,
+    // Total time the animation spent paused, since `started_at` gets pushed forward by it.
+    paused_for: Duration,
+    // Total time the animation gained by playing faster, since `started_at` gets pulled back by it.
+    sped_up_for: Duration,
+    // How fast the animation plays, `started_at` gets pushed forward by the time it loses.
+    speed: f64,
+    // Called when the animation state changes
+    on_state_change: Option<Callback<AnimationState>>,
+    // Called with the new value when the animation progressed by at least `update_threshold`
+    on_update: Option<Callback<T>>,
+    update_threshold: f64,
+    last_update_progress: Option<f64>,
+    // Value changes smaller than this are ignored by `tick_changed`.
+    value_epsilon: f64,
+    // The value `tick_changed` last reported, compared with the next ones so small changes add up.
+    reported_value: Option<T>,
+    // How many more times the animation plays after the first time, with a delay between each.
+    repeat: u32,
+    repeat_delay: Duration,
+    // The current iteration, from 0 to repeat.
+    iteration: u32,
+    // Called with the index of the completed iteration when the animation repeats.
+    on_loop: Option<Callback<u32>>,
+    // If set, the animation pauses once its time progress reaches this value.
+    pause_at: Option<f64>,
+    // If set, large tick deltas are split into steps of at most this duration.
+    catch_up_step: Option<Duration>,
+    // Whether the last tick is the one that finished the animation
+    just_finished: bool,
+    // Whether the animation was finished when it got paused, so that `is_finished` doesn't depend
+    // on how the paused ticks shift `started_at`.
+    finished_when_paused: Option<bool>,
 }
 
 impl<T: Animable> Animation<T> {
+    /// Repeat count to make an animation repeat forever, see [`Animation::with_repeat`].
+    pub const REPEAT_FOREVER: u32 = u32::MAX;
+
     /// Creates a new animation with given parameters.
     ///
     /// This returns None if `start == end`
     pub fn new(start: T, end: T, duration: Duration) -> Self {
-        let started_at = get_monotonic_time();
+        Self::new_at(start, end, duration, get_monotonic_time())
+    }
+
+    /// Creates a new animation that never reads the system clock.
+    ///
+    /// The animation starts at [`Duration::ZERO`], and must always be driven with explicit
+    /// timestamps from this origin, with [`Animation::tick`] or [`Animation::tick_delta`]. This is
+    /// useful for headless rendering, or in sandboxes where the clock syscall is denied.
+    ///
+    /// NOTE: [`Animation::restart`] reads the system clock.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let mut animation = Animation::headless(0.0, 10.0, Duration::from_secs(1));
+    /// animation.tick(Duration::from_millis(500));
+    /// assert_eq!(*animation.value(), 5.0);
+    /// animation.tick(Duration::from_secs(1));
+    /// assert!(animation.is_finished());
+    /// ```
+    pub fn headless(start: T, end: T, duration: Duration) -> Self {
+        Self::new_at(start, end, duration, Duration::ZERO)
+    }
+
+    /// Creates a new animation that started at `started_at`.
+    fn new_at(start: T, end: T, duration: Duration, started_at: Duration) -> Self {
         let current_value = start.clone();
 
         Self {
             start,
             end,
+            name: None,
             current_value,
 
             state: AnimationState::Running,
 
             curve: AnimationCurve::default(),
+            fps_quantization: None,
+            max_overshoot: None,
+            unclamped: false,
+            progress_cache: None,
+            bounds: None,
             started_at,
             last_tick: started_at,
+            clock_offset: Duration::ZERO,
             duration,
+            delay: Duration::ZERO,
+            paused_for: Duration::ZERO,
+            sped_up_for: Duration::ZERO,
+            speed: 1.0,
+            on_state_change: None,
+            on_update: None,
+            update_threshold: 0.0,
+            value_epsilon: 0.0,
+            reported_value: None,
+            last_update_progress: None,
+            repeat: 0,
+            repeat_delay: Duration::ZERO,
+            iteration: 0,
+            on_loop: None,
+            pause_at: None,
+            catch_up_step: None,
+            just_finished: false,
+            finished_when_paused: None,
         }
     }
 
+    /// Change the animation name in-place.
+    ///
+    /// The name is purely diagnostic, it shows up in the [`Debug`](std::fmt::Debug) output.
+    pub fn with_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the animation name.
+    ///
+    /// See [`Animation::with_name`]
+    pub fn set_name(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.name = Some(name.into());
+    }
+
+    /// Get the animation name, if any.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Change the animation state in-place.
     pub fn with_state(mut self, state: AnimationState) -> Self {
-        self.state = state;
+        self.set_state(state);
         self
     }
 
     /// Set the animation state.
     pub fn set_state(&mut self, state: AnimationState) {
+        self.finished_when_paused = match state {
+            // Keep the determination we had before pausing, if the animation was already paused.
+            AnimationState::Paused => Some(
+                self.finished_when_paused
+                    .unwrap_or_else(|| self.reached_end()),
+            ),
+            AnimationState::Running | AnimationState::Finished => None,
+        };
+
+        let changed = self.state != state;
         self.state = state;
+        if changed {
+            if let Some(on_state_change) = &self.on_state_change {
+                on_state_change.call(state);
+            }
+        }
+    }
+
+    /// Get the animation state.
+    #[inline]
+    pub fn state(&self) -> AnimationState {
+        self.state
+    }
+
+    /// Toggle the animation state between running and paused.
+    ///
+    /// A finished animation stays finished, use [`Animation::restart`] instead.
+    pub fn toggle_state(&mut self) {
+        self.set_state(-self.state);
+    }
+
+    /// Change the callback invoked when the animation state changes in-place.
+    ///
+    /// The callback only fires on actual transitions, setting the same state again will not.
+    pub fn with_on_state_change(
+        mut self,
+        on_state_change: impl FnMut(AnimationState) + Send + 'static,
+    ) -> Self {
+        self.set_on_state_change(on_state_change);
+        self
+    }
+
+    /// Set the callback invoked when the animation state changes.
+    ///
+    /// See [`Animation::with_on_state_change`]
+    pub fn set_on_state_change(
+        &mut self,
+        on_state_change: impl FnMut(AnimationState) + Send + 'static,
+    ) {
+        self.on_state_change = Some(Callback::new(on_state_change));
+    }
+
+    /// Change the callback invoked with the new value when the animation ticks in-place.
+    ///
+    /// See [`Animation::set_progress_callback_threshold`] to throttle how often it gets called.
+    pub fn with_on_update(mut self, on_update: impl FnMut(T) + Send + 'static) -> Self {
+        self.set_on_update(on_update);
+        self
+    }
+
+    /// Set the callback invoked with the new value when the animation ticks.
+    ///
+    /// See [`Animation::with_on_update`]
+    pub fn set_on_update(&mut self, on_update: impl FnMut(T) + Send + 'static) {
+        self.on_update = Some(Callback::new(on_update));
+        self.last_update_progress = None;
+    }
+
+    /// Only invoke the update callback when the time progress advanced by at least `threshold`.
+    ///
+    /// This is useful to throttle expensive work done in the callback, for example with a
+    /// threshold of `0.1`, the callback runs about 10 times over the whole animation. The final
+    /// value is always reported. The default threshold is `0.0`, calling back on every tick.
+    pub fn set_progress_callback_threshold(&mut self, threshold: f64) {
+        self.update_threshold = threshold;
+    }
+
+    /// Make [`Animation::tick_changed`] ignore value changes smaller than `epsilon` in-place.
+    ///
+    /// Changes are measured with [`Animable::distance`], so this does nothing for types without
+    /// one. This is useful to skip redrawing for float noise, for example near the end of an
+    /// easing. The default epsilon is `0.0`, reporting every change.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// let mut animation =
+    ///     Animation::new(0.0, 1.0, Duration::from_secs(1000)).with_value_epsilon(0.01);
+    /// let now = get_monotonic_time();
+    /// assert!(!animation.tick_changed(now + Duration::from_millis(1)));
+    /// assert!(animation.tick_changed(now + Duration::from_secs(100)));
+    /// ```
+    pub fn with_value_epsilon(mut self, epsilon: f64) -> Self {
+        self.set_value_epsilon(epsilon);
+        self
+    }
+
+    /// Set the smallest value change reported by [`Animation::tick_changed`].
+    ///
+    /// See [`Animation::with_value_epsilon`]
+    pub fn set_value_epsilon(&mut self, epsilon: f64) {
+        self.value_epsilon = epsilon;
+    }
+
+    /// Make the animation play `count` more times after the first one in-place.
+    ///
+    /// Each iteration plays the animation from `start` to `end` again. The timing getters, like
+    /// [`Animation::time_progress`], are relative to the current iteration, use
+    /// [`Animation::total_duration`] to get the duration of all of them.
+    ///
+    /// A `count` of [`Animation::REPEAT_FOREVER`] makes the animation never finish.
+    pub fn with_repeat(mut self, count: u32) -> Self {
+        self.set_repeat(count);
+        self
+    }
+
+    /// Set how many more times the animation plays after the first one.
+    ///
+    /// See [`Animation::with_repeat`]
+    pub fn set_repeat(&mut self, count: u32) {
+        self.repeat = count;
+    }
+
+    /// Make the animation hold at `end` for `delay` between each iteration in-place.
+    ///
+    /// There's no delay after the last iteration, the animation finishes right away.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// // Pulse 3 times, waiting 2 seconds between each pulse.
+    /// let pulse = Animation::new(0.0, 1.0, Duration::from_millis(500))
+    ///     .with_repeat(2)
+    ///     .with_repeat_delay(Duration::from_secs(2));
+    /// assert_eq!(pulse.total_duration(), Duration::from_millis(5500));
+    /// ```
+    pub fn with_repeat_delay(mut self, delay: Duration) -> Self {
+        self.set_repeat_delay(delay);
+        self
+    }
+
+    /// Set how long the animation holds at `end` between each iteration.
+    ///
+    /// See [`Animation::with_repeat_delay`]
+    pub fn set_repeat_delay(&mut self, delay: Duration) {
+        self.repeat_delay = delay;
+    }
+
+    /// Change the callback invoked each time the animation repeats in-place.
+    ///
+    /// The callback gets the index of the iteration that just completed, starting from `0`. It
+    /// doesn't fire after the last iteration, use [`Animation::with_on_state_change`] to know
+    /// when the animation finishes. If a single tick skips more than 1024 iterations, only the
+    /// last 1024 are reported.
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicU32, Ordering};
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// let loops = Arc::new(AtomicU32::new(0));
+    /// let counter = Arc::clone(&loops);
+    /// let mut carousel = Animation::new(0.0, 1.0, Duration::from_millis(100))
+    ///     .with_repeat(3)
+    ///     .with_on_loop(move |_| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    ///
+    /// carousel.tick(get_monotonic_time() + Duration::from_secs(1));
+    /// assert_eq!(loops.load(Ordering::Relaxed), 3);
+    /// ```
+    pub fn with_on_loop(mut self, on_loop: impl FnMut(u32) + Send + 'static) -> Self {
+        self.set_on_loop(on_loop);
+        self
+    }
+
+    /// Set the callback invoked each time the animation repeats.
+    ///
+    /// See [`Animation::with_on_loop`]
+    pub fn set_on_loop(&mut self, on_loop: impl FnMut(u32) + Send + 'static) {
+        self.on_loop = Some(Callback::new(on_loop));
+    }
+
+    /// Make the animation wait for `delay` before playing in-place.
+    ///
+    /// The animation holds at `start` during the delay, which is applied again when restarting it.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.set_delay(delay);
+        self
+    }
+
+    /// Set how long the animation waits before playing.
+    ///
+    /// See [`Animation::with_delay`]
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.started_at = self.started_at.saturating_sub(self.delay) + delay;
+        self.delay = delay;
+    }
+
+    /// Make the animation wait for its turn in a list of staggered animations in-place.
+    ///
+    /// This is a shorthand for [`Animation::with_delay`] with `index * per_item`, for sibling
+    /// animations that should start one after the other.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let items = (0..5)
+    ///     .map(|i| {
+    ///         Animation::new(0.0, 1.0, Duration::from_millis(200))
+    ///             .with_stagger(i, Duration::from_millis(50))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(items[3].delay(), Duration::from_millis(150));
+    /// ```
+    pub fn with_stagger(self, index: usize, per_item: Duration) -> Self {
+        let index = u32::try_from(index).unwrap_or(u32::MAX);
+        self.with_delay(per_item.saturating_mul(index))
+    }
+
+    /// Get how long the animation waits before playing.
+    #[inline]
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Make the animation pause once its time progress reaches `progress` in-place.
+    ///
+    /// The animation holds exactly at `progress` until you resume it with
+    /// [`Animation::set_state`], for example a loading bar that fills up to 80% while waiting for
+    /// data, then completes. This only happens once, resuming the animation plays it to the end.
+    pub fn with_pause_at(mut self, progress: f64) -> Self {
+        self.set_pause_at(Some(progress));
+        self
+    }
+
+    /// Set the time progress to pause the animation at, or `None` to not pause it.
+    ///
+    /// See [`Animation::with_pause_at`]
+    pub fn set_pause_at(&mut self, progress: Option<f64>) {
+        // Duration::mul_f64 panics with NaN or negative values.
+        self.pause_at = progress.filter(|p| !p.is_nan()).map(|p| p.clamp(0.0, 1.0));
+    }
+
+    /// Make the animation catch up on missed frames in-place.
+    ///
+    /// When ticking the animation with a time delta larger than `step`, for example after a stall,
+    /// the delta gets split into sub-steps of at most `step`, each of them updating the value and
+    /// calling the update callback. This is useful when the callback has side effects that must be
+    /// applied incrementally, like an audio parameter that clicks on large jumps.
+    ///
+    /// Only the last 1024 sub-steps are simulated, so that catching up after a long stall, for
+    /// example when the system resumes from suspend, doesn't hang.
+    ///
+    /// NOTE: The update callback threshold still applies to each sub-step.
+    pub fn with_catch_up(mut self, step: Duration) -> Self {
+        self.set_catch_up(Some(step));
+        self
+    }
+
+    /// Set the maximum time step to catch up on missed frames with, or `None` to not catch up.
+    ///
+    /// See [`Animation::with_catch_up`]
+    pub fn set_catch_up(&mut self, step: Option<Duration>) {
+        self.catch_up_step = step;
     }
 
     /// Change the animation duration in-place.
@@ -148,6 +906,7 @@ impl<T: Animable> Animation<T> {
     pub fn with_duration(mut self, duration: Duration) -> Self {
         if !matches!(self.curve, AnimationCurve::Spring(_)) {
             self.duration = duration;
+            self.progress_cache = None;
         }
         self
     }
@@ -159,52 +918,426 @@ impl<T: Animable> Animation<T> {
     pub fn set_duration(&mut self, duration: Duration) {
         if !matches!(self.curve, AnimationCurve::Spring(_)) {
             self.duration = duration;
+            self.progress_cache = None;
         }
     }
 
-    /// Change the animation curve in-place.
-    pub fn with_curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
-        let curve = curve.into();
-        if let AnimationCurve::Spring(spring) = &curve {
-            self.duration = spring.duration();
-        }
-        self.curve = curve;
+    /// Make the animation play at `speed` times its normal speed in-place.
+    ///
+    /// This is useful for debugging, for example to watch animations at 10% speed with a speed of
+    /// `0.1`. The speed only applies to the time elapsed after changing it, so changing it in the
+    /// middle of the animation doesn't make the value jump. The time lost by slowing down counts
+    /// as paused, see [`Animation::paused_duration`].
+    ///
+    /// NOTE: Speeds that are not strictly positive and finite are ignored, pause the animation
+    /// instead.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// let mut animation = Animation::new(0.0_f64, 1.0, Duration::from_millis(1000));
+    /// let now = get_monotonic_time();
+    /// animation.tick(now + Duration::from_millis(500));
+    /// let value = *animation.value();
+    ///
+    /// animation.set_speed(0.1);
+    /// animation.tick(now + Duration::from_millis(500));
+    /// assert_eq!(*animation.value(), value);
+    /// animation.tick(now + Duration::from_millis(600));
+    /// assert!((animation.value() - value - 0.01).abs() < 1e-9);
+    /// ```
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.set_speed(speed);
         self
     }
 
-    /// Set the animation curve.
-    pub fn set_curve(&mut self, curve: impl Into<AnimationCurve>) {
-        let curve = curve.into();
-        if let AnimationCurve::Spring(spring) = &curve {
-            self.duration = spring.duration();
+    /// Set how fast the animation plays, relative to its normal speed.
+    ///
+    /// See [`Animation::with_speed`]
+    pub fn set_speed(&mut self, speed: f64) {
+        if speed > 0.0 && speed.is_finite() {
+            self.speed = speed;
         }
-        self.curve = curve;
     }
 
-    /// Restart the time state of the animation.
-    pub fn restart(&mut self) {
-        self.last_tick = get_monotonic_time();
-        self.started_at = self.last_tick;
+    /// Get how fast the animation plays, relative to its normal speed.
+    #[inline]
+    pub fn speed(&self) -> f64 {
+        self.speed
     }
 
-    /// Tick the animation at a given [`Duration`], relative to `UNIX_EPOCH`
+    /// Set the animation duration, failing if the curve derives its own duration.
     ///
-    /// It is assumed that the value from `now` is coming from a monotonically increasing system
-    /// clock, for example libc's `clock_gettime(CLOCK_MONOTONIC)` on UNIX.
-    pub fn tick(&mut self, now: Duration) {
-        if self.state == AnimationState::Paused {
-            // This is adapted from slowdown animation code inside niri (yalter/niri)
-            // But, to pause an animation, ANIMATION_SLOWDOWN must approach +inf, so adjusted_delta
-            // (in the original niri code) becomes 0 (with limits)
+    /// Unlike [`Animation::set_duration`], which silently ignores the duration for `Spring`
+    /// curves, this returns [`AnimationError::CurveMismatch`] so that you know it got ignored.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{Animation, AnimationError};
+    /// let mut animation = Animation::new(0.0, 1.0, Duration::from_millis(200))
+    ///     .with_cubic((0.25, 0.1), (0.25, 1.0));
+    /// assert_eq!(animation.try_set_duration(Duration::from_millis(300)), Ok(()));
+    ///
+    /// let mut animation = animation.with_spring(0.8, 800.0);
+    /// assert_eq!(
+    ///     animation.try_set_duration(Duration::from_millis(300)),
+    ///     Err(AnimationError::CurveMismatch),
+    /// );
+    /// ```
+    pub fn try_set_duration(&mut self, duration: Duration) -> Result<(), AnimationError> {
+        if matches!(self.curve, AnimationCurve::Spring(_)) {
+            return Err(AnimationError::CurveMismatch);
+        }
+
+        self.set_duration(duration);
+        Ok(())
+    }
+
+    /// Change the animation duration in-place so that it moves at `units_per_second`.
+    ///
+    /// The duration is computed from the distance between `start` and `end`, so that animations
+    /// travelling further take longer, with the same speed. This is useful for moving windows, for
+    /// example, where short moves should not look sluggish.
+    ///
+    /// NOTE: This changes nothing if `T` has no distance (see [`Animable::distance`]), if the
+    /// speed is not strictly positive, or if you are using a `Spring` curve.
+    pub fn with_constant_speed(mut self, units_per_second: f64) -> Self {
+        self.set_constant_speed(units_per_second);
+        self
+    }
+
+    /// Set the animation duration so that it moves at `units_per_second`.
+    ///
+    /// See [`Animation::with_constant_speed`]
+    pub fn set_constant_speed(&mut self, units_per_second: f64) {
+        let Some(distance) = T::distance(&self.start, &self.end) else {
+            return;
+        };
+        if units_per_second <= 0.0 {
+            return;
+        }
+
+        if let Ok(duration) = Duration::try_from_secs_f64(distance / units_per_second) {
+            self.set_duration(duration);
+        }
+    }
+
+    /// Change the animation duration, preserving its current progress.
+    ///
+    /// Unlike [`Animation::set_duration`], the animation will visually continue from the same
+    /// point, only faster or slower.
+    ///
+    /// NOTE: If you are using a `Spring` curve, this will change absolutely nothing, as the
+    /// duration for springs is determined by their parameters instead.
+    pub fn retimed_to(&mut self, new_duration: Duration) {
+        if matches!(self.curve, AnimationCurve::Spring(_)) {
+            return;
+        }
+
+        self.rewind_to(new_duration.mul_f64(self.time_progress()));
+        self.duration = new_duration;
+        self.progress_cache = None;
+    }
+
+    /// Change the animation curve in-place.
+    pub fn with_curve(mut self, curve: impl Into<AnimationCurve>) -> Self {
+        let curve = curve.into();
+        if let AnimationCurve::Spring(spring) = &curve {
+            self.duration = spring.duration();
+        }
+        self.curve = curve;
+        self.progress_cache = None;
+        self
+    }
+
+    /// Set the animation curve.
+    pub fn set_curve(&mut self, curve: impl Into<AnimationCurve>) {
+        let curve = curve.into();
+        if let AnimationCurve::Spring(spring) = &curve {
+            self.duration = spring.duration();
+        }
+        self.curve = curve;
+        self.progress_cache = None;
+    }
+
+    /// Change the animation curve in-place to a cubic bezier curve with the given control points.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let animation = Animation::new(0.0, 1.0, Duration::from_millis(200))
+    ///     .with_cubic((0.25, 0.1), (0.25, 1.0));
+    /// ```
+    pub fn with_cubic(
+        self,
+        p1: impl Into<curve::cubic::ControlPoint>,
+        p2: impl Into<curve::cubic::ControlPoint>,
+    ) -> Self {
+        self.with_curve(CubicCurve::new(p1, p2))
+    }
+
+    /// Change the animation curve in-place to a spring with the given damping ratio and stiffness.
+    ///
+    /// The spring has a mass of `1.0`, no initial velocity and doesn't clamp. Use
+    /// [`Animation::with_curve`] with a [`SpringCurve`] to configure the rest.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let animation = Animation::new(0.0, 1.0, Duration::ZERO).with_spring(0.8, 800.0);
+    /// ```
+    pub fn with_spring(self, damping_ratio: f64, stiffness: f64) -> Self {
+        self.with_curve(SpringCurve::new(
+            0.0,
+            false,
+            1.0,
+            damping_ratio,
+            stiffness,
+            None,
+        ))
+    }
+
+    /// Change the animation curve in-place to the given easing.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// use fht_animation::curve::Easing;
+    ///
+    /// let animation = Animation::new(0.0, 1.0, Duration::from_millis(200))
+    ///     .with_easing(Easing::EaseOutCubic);
+    /// ```
+    pub fn with_easing(self, easing: curve::Easing) -> Self {
+        self.with_curve(easing)
+    }
+
+    /// Make the animation update only `fps` times per second in-place.
+    ///
+    /// The elapsed time is quantized to `1 / fps` steps when ticking, which gives the animation a
+    /// deliberately choppy look, for example for pixel-art or retro aesthetics. The animation still
+    /// lands on its end value when finished.
+    pub fn with_fps_quantization(mut self, fps: u32) -> Self {
+        self.set_fps_quantization(Some(fps));
+        self
+    }
+
+    /// Set how many times per second the animation updates, or `None` to update every tick.
+    ///
+    /// See [`Animation::with_fps_quantization`]
+    pub fn set_fps_quantization(&mut self, fps: Option<u32>) {
+        self.fps_quantization = fps;
+        self.progress_cache = None;
+    }
+
+    /// Limit how much the animation curve can overshoot in-place.
+    ///
+    /// The curve progress gets clamped to `[-factor, 1.0 + factor]` before interpolating, so a
+    /// `factor` of `0.15` never lets the animation go more than 15% past its start or end. Unlike
+    /// [`Animation::with_bounds`], this works in progress space and applies to all curves, keeping
+    /// the overshoot character of springs and back easings while capping its amplitude.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// use fht_animation::curve::Easing;
+    ///
+    /// let animation = Animation::new(0.0, 100.0, Duration::from_millis(200))
+    ///     .with_easing(Easing::EaseOutBackCustom(3.0))
+    ///     .with_max_overshoot(0.15);
+    /// let halfway = fht_animation::get_monotonic_time() + Duration::from_millis(100);
+    /// assert!(animation.peek(halfway) <= 115.0);
+    /// ```
+    pub fn with_max_overshoot(mut self, factor: f64) -> Self {
+        self.set_max_overshoot(Some(factor));
+        self
+    }
+
+    /// Set how much the animation curve can overshoot, or `None` to not limit it.
+    ///
+    /// See [`Animation::with_max_overshoot`]
+    pub fn set_max_overshoot(&mut self, factor: Option<f64>) {
+        self.max_overshoot = factor;
+        self.progress_cache = None;
+    }
+
+    /// Make the animation curve extrapolate past its timeline in-place.
+    ///
+    /// By default, the elapsed time is clamped to the animation duration before evaluating easings
+    /// and cubic curves, and the animation lands exactly on `end`. When `unclamped` is set, the
+    /// curve is evaluated with the raw time progress instead, so the tick that finishes the
+    /// animation can extrapolate past `end`. Springs are not affected, since they aren't evaluated
+    /// on a normalized timeline.
+    ///
+    /// NOTE: Easings are not designed to be evaluated outside of `[0.0, 1.0]`, and polynomial ones
+    /// grow really fast past it. Expect wild values if you tick the animation late, and consider
+    /// using [`Animation::with_max_overshoot`] alongside this.
+    pub fn with_unclamped(mut self, unclamped: bool) -> Self {
+        self.set_unclamped(unclamped);
+        self
+    }
+
+    /// Set whether the animation curve extrapolates past its timeline.
+    ///
+    /// See [`Animation::with_unclamped`]
+    pub fn set_unclamped(&mut self, unclamped: bool) {
+        self.unclamped = unclamped;
+        self.progress_cache = None;
+    }
+
+    /// Precompute all the derived values of the animation.
+    ///
+    /// Most of the heavy work is already done eagerly: cubic curves are baked in
+    /// [`CubicCurve::new`], and spring durations are solved in [`Animation::with_curve`] and
+    /// [`Animation::set_curve`]. What's left is evaluating the curve for the current time, which
+    /// this function does and caches, so that the next [`Animation::tick`] at the same time is
+    /// cheap.
+    pub fn prewarm(&mut self) {
+        let elapsed = self.quantized(self.elapsed_effective().as_secs_f64());
+        let progress = self.progress_at(elapsed);
+        self.progress_cache = Some((elapsed, progress));
+        self.current_value = self.value_for_progress(progress);
+    }
+
+    /// Check whether the curve evaluation for the current time is cached.
+    ///
+    /// See [`Animation::prewarm`]
+    pub fn is_prewarmed(&self) -> bool {
+        let elapsed = self.quantized(self.elapsed_effective().as_secs_f64());
+        self.progress_cache
+            .is_some_and(|(x, _)| (x - elapsed).abs() <= f64::EPSILON)
+    }
+
+    /// Replace the animation curve with a spring, keeping the current motion.
+    ///
+    /// Unlike [`Animation::set_curve`], the new spring starts from the current progress and
+    /// inherits the current velocity, so that the motion stays continuous. The animation then
+    /// restarts with the new spring from the last tick, without waiting for its delay again.
+    pub fn replace_spring(&mut self, new: SpringCurve) {
+        let (position, velocity) = if self.is_finished() {
+            (1.0, 0.0)
+        } else {
+            let elapsed = self.quantized(self.elapsed_effective().as_secs_f64());
+            (self.progress_at(elapsed), self.velocity())
+        };
+
+        let spring = new
+            .with_initial_position(position)
+            .with_initial_velocity(velocity);
+        self.set_curve(spring);
+        self.restart_in_place();
+    }
+
+    /// Remove the value bounds of the animation, if any.
+    pub fn clear_bounds(&mut self) {
+        self.bounds = None;
+    }
+
+    /// Restart the time state of the animation.
+    pub fn restart(&mut self) {
+        self.restart_at(get_monotonic_time());
+    }
+
+    /// Restart the animation from its current value to `end`.
+    ///
+    /// Unlike [`Animation::restart`], the value doesn't jump back to `start`, since the current
+    /// value becomes the new start. The clock restarts from the last tick, without waiting for the
+    /// animation delay again, so the next tick continues from where the animation is.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let mut animation = Animation::headless(0.0, 10.0, Duration::from_secs(1));
+    /// animation.tick(Duration::from_millis(600));
+    /// let value = *animation.value();
+    ///
+    /// animation.restart_from_current();
+    /// animation.tick(Duration::from_millis(600));
+    /// assert_eq!(*animation.value(), value);
+    /// animation.tick(Duration::from_millis(1600));
+    /// assert_eq!(*animation.value(), 10.0);
+    /// ```
+    pub fn restart_from_current(&mut self) {
+        self.start = self.current_value.clone();
+        self.progress_cache = None;
+        self.restart_in_place();
+    }
+
+    /// Restart the time state of the animation, as if it started at `now`.
+    pub(crate) fn restart_at(&mut self, now: Duration) {
+        self.clock_offset = Duration::ZERO;
+        self.last_tick = now;
+        self.restart_in_place();
+        self.started_at += self.delay;
+        self.iteration = 0;
+    }
+
+    /// Restart the time state of the animation from the last tick.
+    ///
+    /// Unlike [`Animation::restart_at`], this doesn't apply the delay, and keeps the current
+    /// iteration, since the animation continues rather than starting over.
+    fn restart_in_place(&mut self) {
+        self.started_at = self.last_tick;
+        self.paused_for = Duration::ZERO;
+        self.sped_up_for = Duration::ZERO;
+        if self.finished_when_paused.is_some() {
+            // We just restarted, so we can't be finished.
+            self.finished_when_paused = Some(false);
+        }
+        if self.state == AnimationState::Finished {
+            self.set_state(AnimationState::Running);
+        }
+        self.last_update_progress = None;
+        self.just_finished = false;
+    }
+
+    /// Tick the animation at a given [`Duration`], relative to `UNIX_EPOCH`
+    ///
+    /// It is assumed that the value from `now` is coming from a monotonically increasing system
+    /// clock, for example libc's `clock_gettime(CLOCK_MONOTONIC)` on UNIX. If `now` still happens
+    /// to be before the animation start, for example with an out-of-order timestamp, the animation
+    /// is treated as if it just started.
+    pub fn tick(&mut self, now: Duration) {
+        let mut now = now + self.clock_offset;
+        if let Some(step) = self.catch_up_step.filter(|step| !step.is_zero()) {
+            // Simulate the frames we missed, so that the update callback sees every step.
+            let earliest = now.saturating_sub(step.saturating_mul(MAX_CATCH_UP_STEPS));
+            let mut at = (self.last_tick + step).max(earliest);
+            while self.state == AnimationState::Running && at < now {
+                let clock_offset = self.clock_offset;
+                self.tick_once(at);
+                // Playing faster can move the clock forward, follow it.
+                let shift = self.clock_offset - clock_offset;
+                (at, now) = (at + shift, now + shift);
+                if self.state == AnimationState::Finished {
+                    // Don't go through another tick, it would reset just_finished.
+                    self.last_tick = now;
+                    return;
+                }
+                at += step;
+            }
+        }
+
+        self.tick_once(now);
+    }
+
+    /// Tick the animation once at `now`, see [`Animation::tick`].
+    fn tick_once(&mut self, mut now: Duration) {
+        self.just_finished = false;
+        if self.state == AnimationState::Paused {
+            // This is adapted from slowdown animation code inside niri (yalter/niri)
+            // But, to pause an animation, ANIMATION_SLOWDOWN must approach +inf, so adjusted_delta
+            // (in the original niri code) becomes 0 (with limits)
             //
             // Since `now` is meant to be a monotonically increasing clock, we instead adjust the
             // start_time to match our offset.
             if self.last_tick <= now {
                 let delta = now - self.last_tick;
                 self.started_at += delta;
+                self.paused_for += delta;
             } else {
                 let delta = self.last_tick - now;
-                self.started_at -= delta;
+                self.started_at = self.started_at.saturating_sub(delta);
+                self.paused_for = self.paused_for.saturating_sub(delta);
             }
 
             self.last_tick = now;
@@ -213,57 +1346,1896 @@ impl<T: Animable> Animation<T> {
             return;
         }
 
-        let elapsed = (now - self.started_at).as_secs_f64();
-        let total = self.duration.as_secs_f64();
+        if self.state == AnimationState::Finished {
+            // The value already settled on the last tick.
+            self.last_tick = now;
+            return;
+        }
+
+        if self.speed != 1.0 && self.last_tick < now {
+            // Generalization of the pause above, the animation only consumes part of the delta,
+            // and the rest pushes `started_at` forward like when paused.
+            let delta = now - self.last_tick;
+            let consumed = delta.mul_f64(self.speed);
+            if consumed < delta {
+                self.started_at += delta - consumed;
+                self.paused_for += delta - consumed;
+            } else {
+                let gained = consumed - delta;
+                self.sped_up_for += gained;
+                match self.started_at.checked_sub(gained) {
+                    Some(started_at) => self.started_at = started_at,
+                    None => {
+                        // Like when seeking, move the clock forward instead of going before its
+                        // origin.
+                        let shift = gained - self.started_at;
+                        self.clock_offset += shift;
+                        self.last_tick += shift;
+                        now += shift;
+                        self.started_at = Duration::ZERO;
+                    }
+                }
+            }
+        }
+
+        // Start the next iterations we reached, if the animation repeats.
+        let cycles = self.iterations_in(now.saturating_sub(self.started_at));
+        if cycles > 0 {
+            self.started_at += self.duration.saturating_add(self.repeat_delay) * cycles;
+            let completed = self.iteration..self.iteration + cycles;
+            self.iteration += cycles;
+            if let Some(on_loop) = &self.on_loop {
+                let skipped = cycles.saturating_sub(MAX_LOOP_CALLBACKS);
+                for index in completed.skip(skipped as usize) {
+                    on_loop.call(index);
+                }
+            }
+        }
+
+        let mut reached_pause = false;
+        if let Some(target) = self.pause_at {
+            let target = self.duration.mul_f64(target);
+            let elapsed = now.saturating_sub(self.started_at);
+            if elapsed >= target {
+                // Hold exactly at the target, the time past it counts as paused.
+                self.started_at = now.saturating_sub(target);
+                self.paused_for += elapsed - target;
+                self.pause_at = None;
+                reached_pause = true;
+            }
+        }
+
+        let elapsed = now.saturating_sub(self.started_at).as_secs_f64();
         self.last_tick = now;
 
-        self.current_value = match &mut self.curve {
-            AnimationCurve::Simple(easing) => {
-                // keyframe's easing function take an x value between [0.0, 1.0], so normalize out
-                // x value to these.
-                let x = (elapsed / total).clamp(0., 1.);
-                let progress = easing.y(x);
-                T::lerp(&self.start, &self.end, progress)
+        if self.lands_on_end(elapsed) {
+            if self.bounds.is_none() {
+                self.current_value.clone_from(&self.end);
+            } else {
+                self.current_value = self.bounded(self.end.clone());
+            }
+        } else {
+            let elapsed = self.quantized(elapsed);
+            // Ticking multiple times for the same frame is common, avoid evaluating the curve
+            // again.
+            let progress = match self.progress_cache {
+                Some((x, progress)) if (x - elapsed).abs() <= f64::EPSILON => progress,
+                _ => {
+                    let progress = self.progress_at(elapsed);
+                    self.progress_cache = Some((elapsed, progress));
+                    progress
+                }
+            };
+            if self.bounds.is_none() {
+                // Update the value in-place when we can, avoiding allocations for large types.
+                T::lerp_into(&self.start, &self.end, progress, &mut self.current_value);
+            } else {
+                self.current_value = self.value_for_progress(progress);
+            }
+        }
+
+        self.notify_update();
+        let started = now >= self.started_at;
+        if started && self.reached_end() {
+            self.set_state(AnimationState::Finished);
+            self.just_finished = true;
+        } else if reached_pause {
+            self.set_state(AnimationState::Paused);
+        }
+    }
+
+    /// Get how many of the remaining iterations are over `elapsed` after the current one started.
+    fn iterations_in(&self, elapsed: Duration) -> u32 {
+        let remaining = self.repeat.saturating_sub(self.iteration);
+        let cycle = self.duration.saturating_add(self.repeat_delay);
+        if cycle.is_zero() {
+            // Every iteration is over as soon as it starts.
+            return remaining;
+        }
+
+        (elapsed.as_nanos() / cycle.as_nanos()).min(u128::from(remaining)) as u32
+    }
+
+    /// Call the update callback, if the animation progressed enough since the last call.
+    fn notify_update(&mut self) {
+        if self.on_update.is_none() {
+            return;
+        }
+
+        let progress = self.time_progress();
+        let due = match self.last_update_progress {
+            None => true,
+            // Always notify about the final value, even if we didn't progress enough.
+            Some(last) => {
+                (progress - last).abs() >= self.update_threshold || (progress >= 1.0 && last < 1.0)
+            }
+        };
+
+        if due {
+            self.last_update_progress = Some(progress);
+            if let Some(on_update) = &self.on_update {
+                on_update.call(self.current_value.clone());
+            }
+        }
+    }
+
+    /// Get what the animation value would be if it was ticked at `now`.
+    ///
+    /// Unlike [`Animation::tick`], this doesn't update the animation, which is useful to predict
+    /// where the animation will be on the next frame, for example.
+    pub fn peek(&self, now: Duration) -> T {
+        if self.state != AnimationState::Running {
+            // The value wouldn't change when ticking a paused or finished animation.
+            return self.current_value.clone();
+        }
+
+        // Consume the time delta at the animation speed, like when ticking.
+        let now = now + self.clock_offset;
+        let now = match now.checked_sub(self.last_tick) {
+            Some(delta) => self.last_tick + delta.mul_f64(self.speed),
+            None => now,
+        };
+        let mut elapsed = now.saturating_sub(self.started_at);
+        // Skip the iterations we would start, like when ticking.
+        let cycle = self.duration.saturating_add(self.repeat_delay);
+        elapsed -= cycle * self.iterations_in(elapsed);
+        if let Some(target) = self.pause_at {
+            // Ticking would pause the animation there.
+            elapsed = elapsed.min(self.duration.mul_f64(target));
+        }
+        self.value_at(elapsed.as_secs_f64())
+    }
+
+    /// Get the animation value at `elapsed` seconds since the animation started.
+    fn value_at(&self, elapsed: f64) -> T {
+        if self.lands_on_end(elapsed) {
+            return self.bounded(self.end.clone());
+        }
+
+        self.value_for_progress(self.progress_at(self.quantized(elapsed)))
+    }
+
+    /// Quantize `elapsed` seconds to the animation frame grid, if any.
+    fn quantized(&self, elapsed: f64) -> f64 {
+        match self.fps_quantization {
+            Some(fps) if fps > 0 => {
+                let fps = f64::from(fps);
+                (elapsed * fps).floor() / fps
             }
-            AnimationCurve::Cubic(cubic) => {
-                // Cubic animations also take in X between [0.0, 1.0] and outputs a progress in
-                // [0.0, 1.0]
-                let x = (elapsed / total).clamp(0., 1.);
-                let progress = cubic.y(x);
-                T::lerp(&self.start, &self.end, progress)
+            _ => elapsed,
+        }
+    }
+
+    /// Check whether the animation should land exactly on `end` at `elapsed` seconds.
+    ///
+    /// Interpolating with the final progress of the curve can be fractionally off from `end`, so
+    /// we use `end` directly once the animation is over. Springs are excluded since they already
+    /// settle on their own.
+    fn lands_on_end(&self, elapsed: f64) -> bool {
+        !self.unclamped
+            && !matches!(self.curve, AnimationCurve::Spring(_))
+            && elapsed >= self.duration.as_secs_f64()
+    }
+
+    /// Get the animation value for a given curve `progress`.
+    fn value_for_progress(&self, progress: f64) -> T {
+        self.bounded(T::lerp(&self.start, &self.end, progress))
+    }
+
+    /// Constrain `value` inside the animation bounds, if any.
+    fn bounded(&self, value: T) -> T {
+        match &self.bounds {
+            Some((min, max, clamp)) => clamp(value, min, max),
+            None => value,
+        }
+    }
+
+    /// Move `started_at` so that `elapsed` passed since the animation started at the last tick.
+    fn rewind_to(&mut self, elapsed: Duration) {
+        match self.last_tick.checked_sub(elapsed) {
+            Some(started_at) => self.started_at = started_at,
+            None => {
+                // The animation would start before the clock origin, move the clock forward.
+                let shift = elapsed - self.last_tick;
+                self.clock_offset += shift;
+                self.last_tick += shift;
+                self.started_at = Duration::ZERO;
             }
-            AnimationCurve::Spring(spring) => {
-                let progress = spring.oscillate(elapsed);
-                T::lerp(&self.start, &self.end, progress)
+        }
+    }
+
+    /// Move the animation clock so that its value is `target`.
+    ///
+    /// This is useful to resume an animation from a value read off the screen, for example. It
+    /// returns whether seeking succeeded.
+    ///
+    /// NOTE: This only works if `T` can be inverted (see [`Animable::inverse_lerp`]), and if the
+    /// curve is monotonic, which excludes springs that overshoot and back easings.
+    pub fn seek_to_value(&mut self, target: T) -> bool {
+        let Some(target) = T::inverse_lerp(&self.start, &self.end, &target) else {
+            return false;
+        };
+        if self.duration == Duration::MAX {
+            // We can't search inside an animation that never ends.
+            return false;
+        }
+
+        // Since the curve is monotonic, a binary search on the elapsed time is enough.
+        let (mut low, mut high) = (0.0, self.duration.as_secs_f64());
+        for _ in 0..64 {
+            let mid = (low + high) / 2.0;
+            if self.progress_at(mid) < target {
+                low = mid;
+            } else {
+                high = mid;
             }
+        }
+
+        let elapsed = Duration::from_secs_f64(high);
+        self.rewind_to(elapsed);
+        self.current_value = self.value_at(high);
+        if self.finished_when_paused.is_some() {
+            self.finished_when_paused =
+                Some(self.iteration >= self.repeat && elapsed >= self.duration);
+        }
+        if self.state == AnimationState::Finished && elapsed < self.duration {
+            self.set_state(AnimationState::Running);
+        }
+
+        true
+    }
+
+    /// Advance the animation by `dt` since the last tick.
+    ///
+    /// This is useful when your loop provides a frame delta instead of an absolute timestamp, for
+    /// example with fixed-timestep simulations. Both ways of ticking can be mixed together.
+    ///
+    /// NOTE: If the animation is paused, `dt` will be counted as paused time, and the animation
+    /// value will not change.
+    pub fn tick_delta(&mut self, dt: Duration) {
+        self.tick(self.last_tick - self.clock_offset + dt);
+    }
+
+    /// Set the animation value from an external driver, like a scroll position.
+    ///
+    /// `ratio` is the normalized position of the driver, from `0.0` to `1.0`, and is used in place
+    /// of the time progress to evaluate the animation curve. This doesn't touch the animation clock
+    /// nor its state, and doesn't call the update callback.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let mut animation = Animation::new(0.0, 100.0, Duration::from_millis(200));
+    /// let (offset, max) = (250.0, 1000.0);
+    /// animation.drive_from_ratio(offset / max);
+    /// assert_eq!(*animation.value(), 25.0);
+    /// ```
+    pub fn drive_from_ratio(&mut self, ratio: f64) {
+        let elapsed = self.duration.as_secs_f64() * ratio;
+        self.current_value = self.value_at(elapsed);
+    }
+
+    /// Set the animation value from a [`ProgressSource`], like a master animation.
+    ///
+    /// This is [`Animation::drive_from_ratio`] with the progress of `source`.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// # use fht_animation::curve::Easing;
+    /// let duration = Duration::from_millis(300);
+    /// let mut timeline = Animation::new(0.0, 1.0, duration).with_easing(Easing::EaseOutCubic);
+    /// // Both slaves follow the timeline easing, the offset adds its own on top.
+    /// let mut opacity = Animation::new(0.0, 1.0, duration);
+    /// let mut offset = Animation::new(-20.0, 0.0, duration).with_easing(Easing::EaseInQuad);
+    ///
+    /// timeline.tick(get_monotonic_time() + Duration::from_millis(150));
+    /// opacity.drive_from(&timeline);
+    /// offset.drive_from(&timeline);
+    /// assert!((opacity.value() - timeline.eased_progress()).abs() < 1e-9);
+    /// assert!(*offset.value() < -20.0 * (1.0 - timeline.eased_progress()));
+    /// ```
+    pub fn drive_from(&mut self, source: &impl ProgressSource) {
+        self.drive_from_ratio(source.progress());
+    }
+
+    /// Check that the animation configuration is valid.
+    ///
+    /// Animations can be built in pathological states, for example with NaN curve parameters, or
+    /// a spring that never settles. This is useful to fail fast after building animations from
+    /// untrusted configuration.
+    pub fn validate(&self) -> Result<(), AnimationError> {
+        self.curve.validate()?;
+        if self.duration.is_zero() && !matches!(self.curve, AnimationCurve::Spring(_)) {
+            return Err(AnimationError::ZeroDuration);
+        }
+
+        Ok(())
+    }
+
+    /// Get the curve progress at `elapsed` seconds since the animation started.
+    fn progress_at(&self, elapsed: f64) -> f64 {
+        let total = self.duration.as_secs_f64();
+        // Easings and cubic curves take an x value between [0.0, 1.0], so normalize our x value to
+        // these, unless we are asked to extrapolate.
+        let x = if self.unclamped {
+            elapsed / total
+        } else {
+            (elapsed / total).clamp(0., 1.)
+        };
+        let progress = self.curve.progress(x, elapsed);
+
+        match self.max_overshoot {
+            Some(factor) => progress.clamp(-factor, 1.0 + factor),
+            None => progress,
+        }
+    }
+
+    /// Get the current velocity of the animation progress, in progress units per second.
+    ///
+    /// This is estimated from the curve around the last tick, and is `0.0` once the animation is
+    /// finished.
+    pub fn velocity(&self) -> f64 {
+        if self.is_finished() {
+            return 0.0;
+        }
+
+        let elapsed = self.last_tick.saturating_sub(self.started_at).as_secs_f64();
+        // Use a backward difference when we can, so that we report how the value got here.
+        let (x0, x1) = if elapsed >= VELOCITY_DELTA {
+            (elapsed - VELOCITY_DELTA, elapsed)
+        } else {
+            (elapsed, elapsed + VELOCITY_DELTA)
         };
+
+        (self.progress_at(x1) - self.progress_at(x0)) / VELOCITY_DELTA
+    }
+
+    /// Get the direction in which the animation value is currently moving.
+    ///
+    /// This is derived from the sign of [`Animation::velocity`].
+    pub fn direction(&self) -> Direction {
+        let velocity = self.velocity();
+        if velocity.abs() <= f64::EPSILON {
+            Direction::Still
+        } else if velocity > 0.0 {
+            Direction::Forward
+        } else {
+            Direction::Backward
+        }
+    }
+
+    /// Get a suggested interval before the next [`Animation::tick`], based on how fast the
+    /// animation is moving.
+    ///
+    /// Fast animations should be ticked on every frame, but slow tails, like springs that are
+    /// nearly settled, barely move between two frames. This is useful to back off a redraw
+    /// subscription near the end of an animation, saving wakeups. Paused and finished animations
+    /// return [`Duration::MAX`], since ticking them changes nothing.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation, SpringCurve};
+    /// let spring = SpringCurve::new(0.0, false, 1.0, 0.3, 200.0, None);
+    /// let mut animation = Animation::new(0.0, 1.0, Duration::ZERO).with_curve(spring);
+    /// let now = get_monotonic_time();
+    ///
+    /// animation.tick(now + Duration::from_millis(100));
+    /// let bouncing = animation.suggested_tick_interval();
+    /// animation.tick(now + Duration::from_millis(1700));
+    /// let settling = animation.suggested_tick_interval();
+    /// assert!(bouncing < settling);
+    /// ```
+    pub fn suggested_tick_interval(&self) -> Duration {
+        // Keep the progress made between two ticks below this, about half a pixel over 250px.
+        const MAX_PROGRESS_STEP: f64 = 0.002;
+        // A 144Hz frame
+        const MIN_INTERVAL: Duration = Duration::from_micros(6944);
+        const MAX_INTERVAL: Duration = Duration::from_millis(100);
+
+        if self.state == AnimationState::Paused || self.is_finished() {
+            return Duration::MAX;
+        }
+
+        let speed = self.velocity().abs();
+        let interval = Duration::try_from_secs_f64(MAX_PROGRESS_STEP / speed)
+            .unwrap_or(MAX_INTERVAL)
+            .clamp(MIN_INTERVAL, MAX_INTERVAL);
+        // Don't skip past the end of the animation.
+        let remaining = self.duration.saturating_sub(self.elapsed_effective());
+        interval.min(remaining.max(MIN_INTERVAL))
     }
 
     /// Check whether the animation is finished or not.
     #[inline]
     pub fn is_finished(&self) -> bool {
-        self.last_tick - self.started_at >= self.duration
+        if self.state == AnimationState::Finished {
+            return true;
+        }
+
+        // A paused animation stays in the state it was when it got paused.
+        self.finished_when_paused
+            .unwrap_or_else(|| self.reached_end())
     }
 
-    /// Get the current progress of this animation in time, from `0.0` to `1.0`.
-    #[inline]
-    pub fn time_progress(&self) -> f64 {
-        let elapsed = (self.last_tick - self.started_at).as_secs_f64();
-        let total = self.duration.as_secs_f64();
-        (elapsed / total).clamp(0., 1.)
+    /// Check whether the last tick reached the end of the last iteration.
+    fn reached_end(&self) -> bool {
+        self.repeat != Self::REPEAT_FOREVER
+            && self.iteration >= self.repeat
+            && self.elapsed_effective() >= self.duration
     }
 
-    /// Get the last calculated value from [`Animation::tick`].
+    /// Get the total time this animation will take to finish, from when it started.
+    ///
+    /// This is the single authoritative duration of the animation, including its delay, and
+    /// returns [`Duration::MAX`] for animations that never end, for example springs that never
+    /// settle.
     #[inline]
-    pub fn value(&self) -> &T {
-        &self.current_value
+    pub fn total_duration(&self) -> Duration {
+        if self.repeat == Self::REPEAT_FOREVER {
+            return Duration::MAX;
+        }
+
+        let iterations = self.duration.checked_mul(self.repeat);
+        let iterations = iterations.and_then(|total| total.checked_add(self.duration));
+        let delays = self.repeat_delay.checked_mul(self.repeat);
+        iterations
+            .zip(delays)
+            .and_then(|(iterations, delays)| iterations.checked_add(delays))
+            .and_then(|total| total.checked_add(self.delay))
+            .unwrap_or(Duration::MAX)
+    }
+
+    /// Get the time at which the animation will finish, in the same clock as
+    /// [`get_monotonic_time`].
+    ///
+    /// This is useful to schedule a timer for when the animation completes instead of polling
+    /// it. It accounts for the remaining repeats, and returns [`Duration::MAX`] for animations
+    /// that never end, for example springs that never settle.
+    ///
+    /// NOTE: This assumes the animation keeps running at its current speed from now on, pausing
+    /// the animation pushes it back.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// let before = get_monotonic_time();
+    /// let animation = Animation::new(0.0, 1.0, Duration::from_millis(200));
+    /// let after = get_monotonic_time();
+    /// let finishes_at = animation.finishes_at();
+    /// assert!(before + Duration::from_millis(200) <= finishes_at);
+    /// assert!(finishes_at <= after + Duration::from_millis(200));
+    /// ```
+    pub fn finishes_at(&self) -> Duration {
+        if self.repeat == Self::REPEAT_FOREVER {
+            return Duration::MAX;
+        }
+
+        let remaining = self
+            .duration
+            .checked_add(self.repeat_delay)
+            .and_then(|cycle| cycle.checked_mul(self.repeat.saturating_sub(self.iteration)))
+            .and_then(|cycles| cycles.checked_add(self.duration));
+        let Some(remaining) = remaining else {
+            return Duration::MAX;
+        };
+
+        // The animation consumes time at its speed, so scale what remains past the last tick.
+        let end = self.started_at.saturating_add(remaining);
+        let end = match end.checked_sub(self.last_tick) {
+            Some(left) => self.last_tick.saturating_add(left.div_f64(self.speed)),
+            None => end,
+        };
+        end.saturating_sub(self.clock_offset)
+    }
+
+    /// Check whether the last [`Animation::tick`] is the one that finished the animation.
+    ///
+    /// Unlike [`Animation::is_finished`], this is only true for a single tick, which is useful to
+    /// trigger one-shot effects.
+    #[inline]
+    pub fn just_finished(&self) -> bool {
+        self.just_finished
+    }
+
+    /// Get the wall-clock time elapsed since the animation started, including paused time.
+    #[inline]
+    pub fn elapsed_real(&self) -> Duration {
+        (self.elapsed_effective() + self.paused_for).saturating_sub(self.sped_up_for)
+    }
+
+    /// Get the total time the animation spent paused since it started.
+    ///
+    /// This includes the time lost by playing slower than normal, see [`Animation::with_speed`].
+    ///
+    /// When the animation never played faster than normal, this is the difference between
+    /// [`Animation::elapsed_real`] and [`Animation::elapsed_effective`].
+    #[inline]
+    pub fn paused_duration(&self) -> Duration {
+        self.paused_for
+    }
+
+    /// Get the animation time actually consumed since the animation started.
+    ///
+    /// Unlike [`Animation::elapsed_real`], this excludes the time the animation spent paused.
+    #[inline]
+    pub fn elapsed_effective(&self) -> Duration {
+        self.last_tick.saturating_sub(self.started_at)
+    }
+
+    /// Get the current progress of this animation in time, from `0.0` to `1.0`.
+    #[inline]
+    pub fn time_progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            // Avoid dividing by zero, an instant animation is always done.
+            return 1.0;
+        }
+
+        let elapsed = self.elapsed_effective().as_secs_f64();
+        let total = self.duration.as_secs_f64();
+        (elapsed / total).clamp(0., 1.)
+    }
+
+    /// Get the current progress of this animation curve, the eased counterpart of
+    /// [`Animation::time_progress`].
+    ///
+    /// This is the progress used to interpolate the animation value, so it can go outside of
+    /// `[0.0, 1.0]` for curves that overshoot, like springs. This is useful to derive other values
+    /// from the animation with the same easing.
+    pub fn eased_progress(&self) -> f64 {
+        let elapsed = self.elapsed_effective().as_secs_f64();
+        // Like the value, land exactly on the end, and avoid dividing by a zero duration.
+        if self.duration.is_zero() || self.lands_on_end(elapsed) {
+            return 1.0;
+        }
+
+        self.progress_at(self.quantized(elapsed))
+    }
+
+    /// Get the time progress of this animation remapped so that `in_start..in_end` maps to
+    /// `0.0..1.0`.
+    ///
+    /// The result is clamped, so it stays at `0.0` before `in_start` and at `1.0` after `in_end`.
+    /// This is useful for properties that only animate during part of the timeline.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1));
+    /// // Fade in between 20% and 50% of the animation.
+    /// let mut opacity_at = |millis| {
+    ///     animation.tick(Duration::from_millis(millis));
+    ///     animation.remap_progress(0.2, 0.5)
+    /// };
+    /// assert_eq!(opacity_at(100), 0.0);
+    /// assert_eq!(opacity_at(200), 0.0);
+    /// assert!((opacity_at(350) - 0.5).abs() < 1e-9);
+    /// assert_eq!(opacity_at(500), 1.0);
+    /// assert_eq!(opacity_at(800), 1.0);
+    /// ```
+    pub fn remap_progress(&self, in_start: f64, in_end: f64) -> f64 {
+        let progress = self.time_progress();
+        if in_end <= in_start {
+            // An empty range is a step at its start.
+            return if progress >= in_start { 1.0 } else { 0.0 };
+        }
+
+        ((progress - in_start) / (in_end - in_start)).clamp(0., 1.)
+    }
+
+    /// Get the last calculated value from [`Animation::tick`].
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.current_value
+    }
+
+    /// Get the animation `end` if it's finished, otherwise the last calculated value.
+    ///
+    /// This guarantees that reading a settled animation gives exactly its `end` value, constrained
+    /// inside the animation bounds, if any. The value is only cloned in the latter case.
+    pub fn value_or_end(&self) -> Cow<'_, T> {
+        if !self.is_finished() {
+            Cow::Borrowed(&self.current_value)
+        } else if self.bounds.is_some() {
+            Cow::Owned(self.bounded(self.end.clone()))
+        } else {
+            Cow::Borrowed(&self.end)
+        }
+    }
+
+    /// Convert the animation to another value type, preserving its curve, timing and state.
+    ///
+    /// `f` converts the start, end and current values. This is useful to keep an in-flight
+    /// animation when the animated type changes.
+    ///
+    /// NOTE: The update callback and the value bounds depend on `T`, so they are dropped.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let animation = Animation::new(0.0_f32, 10.0, Duration::from_millis(200)).with_repeat(2);
+    /// let animation = animation.map_type(f64::from);
+    /// assert_eq!(animation.end, 10.0_f64);
+    /// assert_eq!(animation.total_duration(), Duration::from_millis(600));
+    /// ```
+    pub fn map_type<U: Animable>(self, f: impl Fn(T) -> U) -> Animation<U> {
+        Animation {
+            start: f(self.start),
+            end: f(self.end),
+            name: self.name,
+            current_value: f(self.current_value),
+            state: self.state,
+            curve: self.curve,
+            fps_quantization: self.fps_quantization,
+            max_overshoot: self.max_overshoot,
+            unclamped: self.unclamped,
+            progress_cache: self.progress_cache,
+            bounds: None,
+            started_at: self.started_at,
+            last_tick: self.last_tick,
+            clock_offset: self.clock_offset,
+            duration: self.duration,
+            delay: self.delay,
+            paused_for: self.paused_for,
+            sped_up_for: self.sped_up_for,
+            speed: self.speed,
+            on_state_change: self.on_state_change,
+            on_update: None,
+            update_threshold: self.update_threshold,
+            last_update_progress: None,
+            value_epsilon: self.value_epsilon,
+            reported_value: None,
+            repeat: self.repeat,
+            repeat_delay: self.repeat_delay,
+            iteration: self.iteration,
+            on_loop: self.on_loop,
+            pause_at: self.pause_at,
+            catch_up_step: self.catch_up_step,
+            just_finished: self.just_finished,
+            finished_when_paused: self.finished_when_paused,
+        }
+    }
+}
+
+impl<T: Animable + std::fmt::Debug> std::fmt::Debug for Animation<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Only show what's relevant to the user, internal timing state is just noise.
+        let mut debug = f.debug_struct("Animation");
+        if let Some(name) = &self.name {
+            debug.field("name", name);
+        }
+        debug
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("value", &self.current_value)
+            .field("state", &self.state)
+            .field("curve", &self.curve)
+            .field("duration", &self.duration)
+            .field("progress", &self.time_progress())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Animation<f64> {
+    /// Get the entry of `table` at the index this animation is currently at.
+    ///
+    /// This lets you animate an index into a lookup table, for example a precomputed color
+    /// gradient, without cloning the table entries. The index is rounded to the nearest integer,
+    /// and clamped to the table bounds, so curves that overshoot hold the first or last entry.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::Animation;
+    /// let gradient = ["#000000", "#555555", "#aaaaaa", "#ffffff"];
+    /// let last = (gradient.len() - 1) as f64;
+    /// let animation = Animation::new(0.0, last, Duration::from_millis(200));
+    /// assert_eq!(*animation.value_indexed(&gradient), "#000000");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `table` is empty.
+    pub fn value_indexed<'a, U>(&self, table: &'a [U]) -> &'a U {
+        assert!(!table.is_empty(), "can't index into an empty table");
+        // Float to int casts saturate, and NaN becomes 0.
+        let index = self.current_value.round().max(0.0) as usize;
+        &table[index.min(table.len() - 1)]
+    }
+}
+
+impl Animation<Duration> {
+    /// Creates a new countdown from `from`, that never goes below `floor`.
+    ///
+    /// The countdown eases out, so it goes down quickly first, then slows down before settling on
+    /// `floor`. This is useful to display the time left before retrying, for example.
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use fht_animation::{get_monotonic_time, Animation};
+    /// let floor = Duration::from_secs(1);
+    /// let from = Duration::from_secs(30);
+    /// let mut countdown = Animation::countdown(from, floor, Duration::from_secs(5));
+    /// countdown.tick(get_monotonic_time() + Duration::from_millis(4900));
+    /// assert_eq!(*countdown.value(), floor);
+    /// ```
+    pub fn countdown(from: Duration, floor: Duration, duration: Duration) -> Self {
+        Self::new(from, Duration::ZERO, duration)
+            .with_easing(curve::Easing::EaseOutCubic)
+            .with_bounds(floor, from.max(floor))
+    }
+}
+
+impl ProgressSource for Animation<f64> {
+    fn progress(&self) -> f64 {
+        self.eased_progress()
+    }
+}
+
+impl<T: Animable + std::ops::Add<Output = T>> Animation<T> {
+    /// Creates a new animation going from `current` by a relative `delta`.
+    ///
+    /// The animation end is `current + delta`, which requires `T` to implement [`std::ops::Add`].
+    pub fn by(current: T, delta: T, duration: Duration) -> Self {
+        let end = current.clone() + delta;
+        Self::new(current, end, duration)
+    }
+}
+
+impl<T: Animable + PartialEq> Animation<T> {
+    /// Check whether this animation has the same configuration as `other`.
+    ///
+    /// This ignores their clock, state, current value and callbacks, which is useful to only
+    /// restart the animations that changed when reloading a configuration.
+    pub fn config_eq(&self, other: &Self) -> bool {
+        // The clamping function is always the same, only compare the values.
+        let bounds = self.bounds.as_ref().map(|(min, max, _)| (min, max));
+        let other_bounds = other.bounds.as_ref().map(|(min, max, _)| (min, max));
+
+        self.start == other.start
+            && self.end == other.end
+            && self.name == other.name
+            && self.curve.config_eq(&other.curve)
+            && self.duration == other.duration
+            && self.fps_quantization == other.fps_quantization
+            && self.max_overshoot == other.max_overshoot
+            && self.unclamped == other.unclamped
+            && self.repeat == other.repeat
+            && self.repeat_delay == other.repeat_delay
+            && self.speed == other.speed
+            && self.delay == other.delay
+            && self.pause_at == other.pause_at
+            && self.catch_up_step == other.catch_up_step
+            && self.value_epsilon == other.value_epsilon
+            && self.update_threshold == other.update_threshold
+            && bounds == other_bounds
+    }
+
+    /// Tick the animation at a given [`Duration`], returning whether its value changed.
+    ///
+    /// This is useful to skip redrawing when the animation is paused or finished, for example.
+    /// Changes smaller than the value epsilon since the last reported change are not reported,
+    /// see [`Animation::with_value_epsilon`]. The value itself always follows the animation.
+    ///
+    /// See [`Animation::tick`]
+    pub fn tick_changed(&mut self, now: Duration) -> bool {
+        let reported = self
+            .reported_value
+            .take()
+            .unwrap_or_else(|| self.current_value.clone());
+        self.tick(now);
+
+        let tiny = T::distance(&reported, &self.current_value)
+            .is_some_and(|distance| distance < self.value_epsilon);
+        // Always report the final value.
+        let changed = self.current_value != reported && (!tiny || self.just_finished);
+        self.reported_value = Some(if changed {
+            self.current_value.clone()
+        } else {
+            reported
+        });
+
+        changed
+    }
+}
+
+impl<T: Animable + PartialOrd> Animation<T> {
+    /// Constrain the animation value between `min` and `max` in-place.
+    ///
+    /// Unlike clamping the progress, this is applied in value-space after the interpolation, so
+    /// curves that overshoot (like springs) will never push the value outside of this range.
+    pub fn with_bounds(mut self, min: T, max: T) -> Self {
+        self.set_bounds(min, max);
+        self
+    }
+
+    /// Set the value bounds of the animation.
+    ///
+    /// See [`Animation::with_bounds`]
+    pub fn set_bounds(&mut self, min: T, max: T) {
+        self.bounds = Some((min, max, clamp_partial_ord::<T>));
+    }
+}
+
+fn clamp_partial_ord<T: Animable + PartialOrd>(value: T, min: &T, max: &T) -> T {
+    if value < *min {
+        min.clone()
+    } else if value > *max {
+        max.clone()
+    } else {
+        value
     }
 }
 
 /// Get the monotonic time to tick an [`Animation`]
 ///
 /// The duration value is the duration since UNIX_EPOCH
+#[cfg(not(target_arch = "wasm32"))]
 pub fn get_monotonic_time() -> Duration {
     let time = rustix::time::clock_gettime(rustix::time::ClockId::Monotonic);
     Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
 }
+
+/// Get the monotonic time to tick an [`Animation`]
+///
+/// In the browser, the duration value is the duration since the page started loading, as
+/// reported by `performance.now()`.
+///
+/// # Panics
+///
+/// This function panics if called outside of a browser window, since there's no clock available.
+#[cfg(target_arch = "wasm32")]
+pub fn get_monotonic_time() -> Duration {
+    let performance = web_sys::window()
+        .and_then(|window| window.performance())
+        .expect("performance.now() should be available");
+    // performance.now() is in milliseconds, with a sub-millisecond precision.
+    Duration::from_secs_f64(performance.now() / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn f16_reaches_endpoints_exactly() {
+        let start = half::f16::from_f32(0.1);
+        let end = half::f16::from_f32(1234.5);
+        assert_eq!(half::f16::lerp(&start, &end, 0.0), start);
+        assert_eq!(half::f16::lerp(&start, &end, 1.0), end);
+
+        let mut animation = Animation::headless(start, end, Duration::from_millis(100));
+        animation.tick(Duration::from_millis(100));
+        assert_eq!(*animation.value(), end);
+    }
+
+    #[test]
+    fn bounds_clamp_spring_overshoot() {
+        let spring = SpringCurve::new(0.0, false, 1.0, 0.3, 200.0, None);
+        let mut bounded = Animation::headless(0.0, 100.0, Duration::ZERO)
+            .with_curve(spring)
+            .with_bounds(0.0, 100.0);
+        let mut unbounded = bounded.clone();
+        unbounded.clear_bounds();
+
+        let mut overshot = false;
+        for millis in (0..2000).step_by(5) {
+            let now = Duration::from_millis(millis);
+            bounded.tick(now);
+            unbounded.tick(now);
+            assert!((0.0..=100.0).contains(bounded.value()));
+            overshot |= *unbounded.value() > 100.0;
+        }
+        assert!(overshot, "the spring should overshoot without bounds");
+    }
+
+    #[test]
+    fn direction_follows_velocity() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1))
+            .with_easing(curve::Easing::EaseInOutQuad);
+        animation.tick(Duration::from_millis(500));
+        assert_eq!(animation.direction(), Direction::Forward);
+        animation.tick(Duration::from_secs(1));
+        assert_eq!(animation.direction(), Direction::Still);
+
+        // A bouncy spring moves back towards its target after overshooting.
+        let spring = SpringCurve::new(0.0, false, 1.0, 0.3, 200.0, None);
+        let mut animation = Animation::headless(0.0, 1.0, Duration::ZERO).with_curve(spring);
+        let mut went_backward = false;
+        for millis in (0..500).step_by(5) {
+            animation.tick(Duration::from_millis(millis));
+            went_backward |= animation.direction() == Direction::Backward;
+        }
+        assert!(went_backward);
+    }
+
+    #[test]
+    fn pausing_splits_real_and_effective_elapsed() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1));
+        animation.tick(Duration::from_millis(200));
+        assert_eq!(animation.elapsed_real(), animation.elapsed_effective());
+
+        animation.set_state(AnimationState::Paused);
+        animation.tick(Duration::from_millis(500));
+        animation.set_state(AnimationState::Running);
+        animation.tick(Duration::from_millis(600));
+        assert_eq!(animation.elapsed_effective(), Duration::from_millis(300));
+        assert_eq!(animation.elapsed_real(), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn nonzero_never_reaches_zero() {
+        use std::num::{NonZeroI32, NonZeroU32};
+
+        let one = NonZeroU32::new(1).unwrap();
+        let ten = NonZeroU32::new(10).unwrap();
+        assert_eq!(NonZeroU32::lerp(&one, &ten, 1.0), ten);
+        for progress in [-0.5, -0.1, 0.0, 0.05] {
+            assert_eq!(NonZeroU32::lerp(&one, &ten, progress), one);
+        }
+
+        // Signed values crossing zero snap to the closest non-zero value with the same sign.
+        let start = NonZeroI32::new(-10).unwrap();
+        let end = NonZeroI32::new(10).unwrap();
+        assert_eq!(NonZeroI32::lerp(&start, &end, 0.5).get(), 1);
+        assert_eq!(NonZeroI32::lerp(&start, &end, 0.49).get(), -1);
+    }
+
+    #[test]
+    fn tick_delta_matches_absolute_ticks() {
+        let mut absolute = Animation::headless(0.0, 100.0, Duration::from_secs(1))
+            .with_easing(curve::Easing::EaseInOutCubic);
+        let mut delta = absolute.clone();
+        for frame in 1..=10 {
+            absolute.tick(Duration::from_millis(frame * 70));
+            delta.tick_delta(Duration::from_millis(70));
+            assert_eq!(absolute.value(), delta.value());
+        }
+
+        // While paused, the delta doesn't move the value.
+        let mut animation = Animation::headless(0.0, 100.0, Duration::from_secs(1));
+        animation.tick_delta(Duration::from_millis(250));
+        animation.set_state(AnimationState::Paused);
+        animation.tick_delta(Duration::from_millis(500));
+        assert_eq!(*animation.value(), 25.0);
+        animation.set_state(AnimationState::Running);
+        animation.tick_delta(Duration::from_millis(250));
+        assert_eq!(*animation.value(), 50.0);
+    }
+
+    #[test]
+    fn paused_near_the_end_is_not_finished() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1));
+        animation.tick(Duration::from_millis(900));
+        animation.set_state(AnimationState::Paused);
+        for millis in [950, 1000, 1500, 5000] {
+            animation.tick(Duration::from_millis(millis));
+            assert!(!animation.is_finished());
+        }
+        // Pausing again keeps the determination made when first pausing.
+        animation.set_state(AnimationState::Paused);
+        assert!(!animation.is_finished());
+
+        animation.set_state(AnimationState::Running);
+        animation.tick(Duration::from_millis(5050));
+        assert!(!animation.is_finished());
+        animation.tick(Duration::from_millis(5100));
+        assert!(animation.is_finished());
+    }
+
+    #[test]
+    fn peek_matches_tick() {
+        let mut animation = Animation::headless(0.0, 100.0, Duration::from_secs(1))
+            .with_easing(curve::Easing::EaseOutCubic)
+            .with_speed(0.5);
+        for millis in [10, 250, 700, 1200, 2500] {
+            let now = Duration::from_millis(millis);
+            let before = *animation.value();
+            let peeked = animation.peek(now);
+            // Peeking doesn't change the animation.
+            assert_eq!(*animation.value(), before);
+            animation.tick(now);
+            assert_eq!(peeked, *animation.value(), "at {millis}ms");
+        }
+    }
+
+    #[test]
+    fn total_duration() {
+        let animation = Animation::headless(0.0, 1.0, Duration::from_millis(200))
+            .with_delay(Duration::from_millis(50))
+            .with_repeat(2)
+            .with_repeat_delay(Duration::from_millis(100));
+        assert_eq!(animation.total_duration(), Duration::from_millis(850));
+
+        // A spring without damping never settles.
+        let spring = SpringCurve::new(0.0, false, 1.0, 0.0, 200.0, None);
+        let animation = Animation::headless(0.0, 1.0, Duration::ZERO).with_curve(spring);
+        assert_eq!(animation.total_duration(), Duration::MAX);
+        let animation = animation.with_repeat(3).with_delay(Duration::from_secs(1));
+        assert_eq!(animation.total_duration(), Duration::MAX);
+
+        let animation = Animation::headless(0.0, 1.0, Duration::from_secs(1));
+        let mut forever = animation
+            .clone()
+            .with_repeat(Animation::<f64>::REPEAT_FOREVER);
+        assert_eq!(forever.total_duration(), Duration::MAX);
+        assert_eq!(forever.finishes_at(), Duration::MAX);
+        forever.tick(Duration::from_secs(10));
+        assert!(!forever.is_finished());
+        // Any overflow means that the animation never ends too.
+        let animation = animation.with_repeat(u32::MAX - 1);
+        assert_eq!(
+            animation.with_repeat_delay(Duration::MAX).total_duration(),
+            Duration::MAX
+        );
+        let animation = Animation::headless(0.0, 1.0, Duration::from_secs(u64::MAX / 2));
+        assert_eq!(animation.with_repeat(2).total_duration(), Duration::MAX);
+    }
+
+    #[test]
+    fn i128_and_u128_near_max() {
+        // f64 would lose the low bits of values this large.
+        let start = i128::MAX - (1 << 40);
+        assert_eq!(i128::lerp(&start, &i128::MAX, 0.0), start);
+        assert_eq!(i128::lerp(&start, &i128::MAX, 0.25), start + (1 << 38));
+        assert_eq!(i128::lerp(&start, &i128::MAX, 1.0), i128::MAX);
+        assert_eq!(i128::lerp(&i128::MAX, &start, 0.5), i128::MAX - (1 << 39));
+        // The whole range does not overflow, and overshooting saturates.
+        assert_eq!(i128::lerp(&i128::MIN, &i128::MAX, 0.5), -1);
+        assert_eq!(i128::lerp(&0, &i128::MAX, 1.5), i128::MAX);
+        assert_eq!(i128::lerp(&0, &i128::MIN, 1.5), i128::MIN);
+
+        let start = u128::MAX - (1 << 40);
+        assert_eq!(u128::lerp(&start, &u128::MAX, 0.25), start + (1 << 38));
+        assert_eq!(u128::lerp(&start, &u128::MAX, 1.0), u128::MAX);
+        assert_eq!(u128::lerp(&0, &u128::MAX, 1.5), u128::MAX);
+        assert_eq!(u128::lerp(&1, &u128::MAX, -0.5), 0);
+    }
+
+    #[test]
+    fn repeated_ticks_reuse_the_cached_progress() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_curve(curve::Easing::EaseOutCubic);
+        animation.tick(Duration::from_millis(50));
+        let (x, progress) = animation.progress_cache.unwrap();
+        assert_eq!(x, 0.05);
+        assert_eq!(*animation.value(), progress);
+
+        // Tamper with the cache: ticking the same frame again must not evaluate the curve.
+        animation.progress_cache = Some((x, 0.25));
+        animation.tick(Duration::from_millis(50));
+        assert_eq!(*animation.value(), 0.25);
+
+        animation.tick(Duration::from_millis(60));
+        assert_eq!(animation.progress_cache.unwrap().0, 0.06);
+        assert_eq!(*animation.value(), curve::Easing::EaseOutCubic.y(0.6));
+
+        animation.set_curve(curve::Easing::Linear);
+        assert!(animation.progress_cache.is_none());
+    }
+
+    #[test]
+    fn overshooting_curves_land_exactly_on_end() {
+        let curves: [AnimationCurve; 3] = [
+            curve::Easing::EaseOutBackCustom(curve::BACK_OVERSHOOT).into(),
+            curve::Easing::EaseInOutBackCustom(4.0).into(),
+            CubicCurve::new((0.3, 1.6), (0.6, 1.4)).into(),
+        ];
+        for curve in curves {
+            let (start, end) = (0.1_f64, 0.7_f64);
+            let mut animation = Animation::headless(start, end, Duration::from_millis(300))
+                .with_curve(curve.clone());
+            animation.tick(Duration::from_millis(150));
+            animation.tick(Duration::from_millis(300));
+            assert_eq!(animation.value().to_bits(), end.to_bits(), "{curve:?}");
+            assert!(animation.is_finished());
+
+            let (start, end) = (
+                color::Rgba8([3, 250, 17, 255]),
+                color::Rgba8([251, 5, 128, 0]),
+            );
+            let mut animation =
+                Animation::headless(start, end, Duration::from_millis(300)).with_curve(curve);
+            animation.tick(Duration::from_millis(299));
+            animation.tick(Duration::from_millis(301));
+            assert_eq!(*animation.value(), end);
+        }
+    }
+
+    #[test]
+    fn state_change_fires_only_on_transitions() {
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_on_state_change({
+                let changes = Arc::clone(&changes);
+                move |state| changes.lock().unwrap().push(state)
+            });
+
+        animation.set_state(AnimationState::Running);
+        animation.set_state(AnimationState::Paused);
+        animation.set_state(AnimationState::Paused);
+        animation.toggle_state();
+        animation.tick(Duration::from_millis(50));
+        animation.tick(Duration::from_millis(100));
+        animation.tick(Duration::from_millis(150));
+        animation.toggle_state();
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [
+                AnimationState::Paused,
+                AnimationState::Running,
+                AnimationState::Finished
+            ]
+        );
+    }
+
+    #[test]
+    fn tick_changed_ignores_delayed_paused_and_finished_ticks() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_delay(Duration::from_millis(50));
+        assert!(!animation.tick_changed(Duration::from_millis(20)));
+        assert!(!animation.tick_changed(Duration::from_millis(50)));
+        assert!(animation.tick_changed(Duration::from_millis(100)));
+
+        animation.set_state(AnimationState::Paused);
+        assert!(!animation.tick_changed(Duration::from_millis(120)));
+        animation.set_state(AnimationState::Running);
+
+        assert!(animation.tick_changed(Duration::from_millis(200)));
+        assert!(animation.is_finished());
+        assert!(!animation.tick_changed(Duration::from_millis(200)));
+        assert!(!animation.tick_changed(Duration::from_millis(300)));
+        assert_eq!(*animation.value(), 1.0);
+    }
+
+    #[test]
+    fn finished_is_a_terminal_state() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100));
+        assert_eq!(animation.state(), AnimationState::Running);
+        animation.tick(Duration::from_millis(99));
+        assert_eq!(animation.state(), AnimationState::Running);
+        animation.tick(Duration::from_millis(100));
+        assert_eq!(animation.state(), AnimationState::Finished);
+
+        assert_eq!(-AnimationState::Finished, AnimationState::Finished);
+        animation.toggle_state();
+        assert_eq!(animation.state(), AnimationState::Finished);
+        animation.tick(Duration::from_millis(150));
+        assert_eq!(animation.state(), AnimationState::Finished);
+        assert_eq!(*animation.value(), 1.0);
+
+        // Only restarting leaves the finished state.
+        animation.restart_at(Duration::from_millis(200));
+        assert_eq!(animation.state(), AnimationState::Running);
+        animation.tick(Duration::from_millis(250));
+        assert_eq!(*animation.value(), 0.5);
+    }
+
+    #[test]
+    fn by_ends_at_current_plus_delta() {
+        let animation = Animation::by(100.0, -20.0, Duration::from_millis(100));
+        assert_eq!((animation.start, animation.end), (100.0, 80.0));
+        assert_eq!(*animation.value(), 100.0);
+
+        let mut animation = Animation::by(5_i32, 20, Duration::from_millis(100));
+        animation.restart_at(Duration::ZERO);
+        animation.tick(Duration::from_millis(100));
+        assert_eq!(*animation.value(), 25);
+
+        let animation = Animation::by(
+            Duration::from_secs(1),
+            Duration::from_millis(500),
+            Duration::ZERO,
+        );
+        assert_eq!(animation.end, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn fps_quantization_changes_on_the_frame_grid() {
+        let mut animation =
+            Animation::headless(0.0, 1.0, Duration::from_secs(1)).with_fps_quantization(12);
+        let mut changes = Vec::new();
+        let mut previous = *animation.value();
+        for ms in 1..=1000 {
+            animation.tick(Duration::from_millis(ms));
+            if *animation.value() != previous {
+                previous = *animation.value();
+                changes.push(ms);
+            }
+        }
+
+        // Eleven frame boundaries, then landing on the end value.
+        assert_eq!(
+            changes,
+            [84, 167, 250, 334, 417, 500, 584, 667, 750, 834, 917, 1000]
+        );
+        assert_eq!(previous, 1.0);
+    }
+
+    #[test]
+    fn retimed_to_preserves_time_progress() {
+        let start = Duration::from_secs(10);
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(400));
+        animation.restart_at(start);
+        animation.tick(start + Duration::from_millis(100));
+        assert_eq!(animation.time_progress(), 0.25);
+
+        animation.retimed_to(Duration::from_millis(800));
+        assert_eq!(animation.time_progress(), 0.25);
+        assert_eq!(*animation.value(), 0.25);
+        // The rest of the animation plays at the new pace.
+        animation.tick(start + Duration::from_millis(200));
+        let progress = animation.time_progress();
+        assert!((progress - 0.375).abs() < 1e-9);
+
+        animation.retimed_to(Duration::from_millis(80));
+        assert!((animation.time_progress() - progress).abs() < 1e-9);
+        animation.tick(start + Duration::from_millis(250));
+        assert!(animation.is_finished());
+    }
+
+    #[test]
+    fn value_or_end_is_exact_once_finished() {
+        let (start, end) = (0.1_f64, 0.7_f64);
+        let mut animation = Animation::headless(start, end, Duration::from_millis(300))
+            .with_curve(curve::Easing::EaseOutBackCustom(curve::BACK_OVERSHOOT));
+        animation.tick(Duration::from_millis(200));
+        assert!(*animation.value_or_end() > end);
+        animation.tick(Duration::from_millis(300));
+        assert_eq!(animation.value_or_end().to_bits(), end.to_bits());
+
+        // A spring settles close to its end, but not on it.
+        let mut animation = Animation::headless(start, end, Duration::ZERO).with_spring(0.5, 300.0);
+        animation.tick(animation.duration);
+        assert!(animation.is_finished());
+        assert_ne!(*animation.value(), end);
+        assert_eq!(*animation.value_or_end(), end);
+
+        // The end is still constrained inside the bounds.
+        let floor = Duration::from_secs(1);
+        let mut countdown =
+            Animation::countdown(Duration::from_secs(30), floor, Duration::from_secs(5));
+        countdown.restart_at(Duration::ZERO);
+        countdown.tick(Duration::from_secs(6));
+        assert_eq!(*countdown.value_or_end(), floor);
+    }
+
+    #[test]
+    fn seeking_before_the_clock_origin() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+        let mut animation = Animation::headless(0.0, 10.0, Duration::from_secs(1));
+        animation.tick(Duration::from_millis(100));
+
+        // The animation would have started 500ms before the clock origin.
+        assert!(animation.seek_to_value(6.0));
+        assert!(close(*animation.value(), 6.0));
+        assert!(close(animation.time_progress(), 0.6));
+        assert!(close(animation.peek(Duration::from_millis(200)), 7.0));
+        let finishes_at = animation.finishes_at().as_secs_f64();
+        assert!(close(finishes_at, 0.5));
+
+        animation.tick(Duration::from_millis(200));
+        assert!(close(*animation.value(), 7.0));
+        animation.tick_delta(Duration::from_millis(100));
+        assert!(close(*animation.value(), 8.0));
+        animation.tick(Duration::from_millis(500));
+        assert!(animation.is_finished());
+
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(400));
+        animation.tick(Duration::from_millis(100));
+        animation.retimed_to(Duration::from_secs(2));
+        assert!(close(animation.time_progress(), 0.25));
+        animation.tick(Duration::from_millis(200));
+        assert!(close(animation.time_progress(), 0.3));
+    }
+
+    #[test]
+    fn prewarmed_first_tick_skips_the_curve() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Counted(Arc<AtomicUsize>);
+        impl keyframe::EasingFunction for Counted {
+            fn y(&self, x: f64) -> f64 {
+                self.0.fetch_add(1, Ordering::Relaxed);
+                x
+            }
+        }
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_curve(AnimationCurve::from_keyframe(Counted(Arc::clone(&calls))));
+        assert!(!animation.is_prewarmed());
+
+        animation.prewarm();
+        assert!(animation.is_prewarmed());
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+        animation.tick(Duration::ZERO);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        animation.tick(Duration::from_millis(50));
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+        assert_eq!(*animation.value(), 0.5);
+        animation.set_curve(curve::Easing::Linear);
+        assert!(!animation.is_prewarmed());
+    }
+
+    #[test]
+    fn name_appears_in_debug() {
+        let animation = Animation::headless(0.0, 1.0, Duration::from_millis(100));
+        assert_eq!(animation.name(), None);
+        assert!(!format!("{animation:?}").contains("name"));
+
+        let animation = animation.with_name(format!("window-{}", 3));
+        assert_eq!(animation.name(), Some("window-3"));
+        assert!(format!("{animation:?}").starts_with(r#"Animation { name: "window-3", start: 0.0"#));
+    }
+
+    #[test]
+    fn lerp_into_updates_in_place() {
+        let start: [f64; 64] = std::array::from_fn(|i| i as f64);
+        let end: [f64; 64] = std::array::from_fn(|i| -(i as f64));
+        let mut out = [f64::NAN; 64];
+        <[f64; 64]>::lerp_into(&start, &end, 0.25, &mut out);
+        assert_eq!(out, <[f64; 64]>::lerp(&start, &end, 0.25));
+        assert_eq!(out[10], 5.0);
+
+        // Ticking a vector animation keeps reusing the same buffer.
+        let mut animation =
+            Animation::headless(start.to_vec(), end.to_vec(), Duration::from_millis(100));
+        animation.tick(Duration::from_millis(10));
+        let buffer = animation.value().as_ptr();
+        for ms in [20, 50, 80, 100] {
+            animation.tick(Duration::from_millis(ms));
+            assert_eq!(animation.value().as_ptr(), buffer);
+        }
+        assert_eq!(animation.value()[..], end[..]);
+    }
+
+    #[test]
+    fn array_lerp_matches_the_collected_implementation() {
+        fn collected<T: Animable + std::fmt::Debug, const N: usize>(
+            start: &[T; N],
+            end: &[T; N],
+            progress: f64,
+        ) -> [T; N] {
+            let values: Vec<T> = start
+                .iter()
+                .zip(end)
+                .map(|(start, end)| T::lerp(start, end, progress))
+                .collect();
+            values.try_into().unwrap()
+        }
+
+        let start = [0.0, 1.5, -3.0, 100.0];
+        let end = [1.0, -1.5, 3.0, 0.0];
+        for progress in [-0.5, 0.0, 0.3, 1.0, 1.7] {
+            let value = <[f64; 4]>::lerp(&start, &end, progress);
+            assert_eq!(value, collected(&start, &end, progress));
+        }
+        let (start, end) = ([[0_i32, 255]; 3], [[255, 0]; 3]);
+        assert_eq!(
+            <[[i32; 2]; 3]>::lerp(&start, &end, 0.4),
+            collected(&start, &end, 0.4)
+        );
+
+        let empty: [f64; 0] = [];
+        assert_eq!(<[f64; 0]>::lerp(&empty, &empty, 0.5), empty);
+        let mut animation = Animation::headless(empty, empty, Duration::from_millis(100));
+        animation.tick(Duration::from_millis(50));
+        animation.tick(Duration::from_millis(100));
+        assert!(animation.is_finished());
+    }
+
+    #[test]
+    fn progress_threshold_throttles_updates() {
+        let run = |threshold: f64| {
+            let updates = Arc::new(Mutex::new(Vec::new()));
+            let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1))
+                .with_on_update({
+                    let updates = Arc::clone(&updates);
+                    move |value| updates.lock().unwrap().push(value)
+                });
+            animation.set_progress_callback_threshold(threshold);
+            for ms in 1..=1000 {
+                animation.tick(Duration::from_millis(ms));
+            }
+            let updates = updates.lock().unwrap().clone();
+            updates
+        };
+
+        assert_eq!(run(0.0).len(), 1000);
+        let updates = run(0.1);
+        // About every tenth of the animation, and the final value.
+        assert!((10..=11).contains(&updates.len()), "{updates:?}");
+        let (last, throttled) = updates.split_last().unwrap();
+        assert!(throttled.windows(2).all(|pair| pair[1] - pair[0] >= 0.099));
+        assert_eq!(*last, 1.0);
+    }
+
+    #[test]
+    fn just_finished_for_exactly_one_tick() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100));
+        let mut finishing = Vec::new();
+        for ms in (0..=200).step_by(10) {
+            animation.tick(Duration::from_millis(ms));
+            if animation.just_finished() {
+                finishing.push(ms);
+            }
+        }
+        assert_eq!(finishing, [100]);
+        assert!(animation.is_finished());
+
+        // Restarting allows finishing again, and catching up doesn't lose the finishing tick.
+        animation.restart_at(Duration::from_secs(1));
+        animation.set_catch_up(Some(Duration::from_millis(16)));
+        animation.tick(Duration::from_millis(1500));
+        assert!(animation.just_finished());
+        animation.tick(Duration::from_millis(1516));
+        assert!(!animation.just_finished());
+    }
+
+    #[test]
+    fn replace_spring_carries_the_motion() {
+        let start = Duration::from_secs(1);
+        let mut animation = Animation::headless(0.0, 100.0, Duration::ZERO)
+            .with_spring(0.8, 200.0)
+            .with_delay(Duration::from_millis(100))
+            .with_repeat(1);
+        animation.restart_at(start);
+        // In the middle of the second iteration.
+        let now = start + Duration::from_millis(150) + animation.duration;
+        animation.tick(now);
+        assert_eq!(animation.iteration, 1);
+        let (value, velocity): (f64, f64) = (*animation.value(), animation.velocity());
+        assert!(velocity > 1.0);
+
+        animation.replace_spring(SpringCurve::new(0.0, false, 1.0, 0.5, 600.0, None));
+        // Neither the delay nor the repeats start over.
+        assert_eq!(animation.elapsed_effective(), Duration::ZERO);
+        assert_eq!(animation.iteration, 1);
+        animation.tick(now);
+        assert!((*animation.value() - value).abs() < 1e-9);
+        // Velocities are finite differences, on both sides of the switch to a stiffer spring.
+        assert!((animation.velocity() - velocity).abs() / velocity < 0.05);
+
+        animation.tick(now + Duration::from_millis(16));
+        assert!(*animation.value() > value);
+    }
+
+    #[test]
+    fn paused_duration_accumulates_pauses() {
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_secs(1));
+        animation.tick(Duration::from_millis(100));
+        assert_eq!(animation.paused_duration(), Duration::ZERO);
+
+        for (pause, resume) in [(100, 200), (300, 350)] {
+            animation.tick(Duration::from_millis(pause));
+            animation.set_state(AnimationState::Paused);
+            animation.tick(Duration::from_millis(resume - 20));
+            animation.tick(Duration::from_millis(resume));
+            animation.set_state(AnimationState::Running);
+        }
+        animation.tick(Duration::from_millis(400));
+        assert_eq!(animation.paused_duration(), Duration::from_millis(150));
+        assert_eq!(animation.elapsed_effective(), Duration::from_millis(250));
+        assert_eq!(animation.elapsed_real(), Duration::from_millis(400));
+
+        animation.restart_at(Duration::from_secs(1));
+        assert_eq!(animation.paused_duration(), Duration::ZERO);
+    }
+
+    #[test]
+    fn unclamped_linear_extrapolates_past_the_end() {
+        let mut clamped = Animation::headless(0.0_f64, 10.0, Duration::from_millis(100));
+        let mut unclamped = clamped.clone().with_unclamped(true);
+
+        clamped.tick(Duration::from_millis(50));
+        unclamped.tick(Duration::from_millis(50));
+        assert_eq!(*clamped.value(), *unclamped.value());
+
+        // A late tick that finishes the animation.
+        clamped.tick(Duration::from_millis(150));
+        unclamped.tick(Duration::from_millis(150));
+        assert_eq!(*clamped.value(), 10.0);
+        assert!((*unclamped.value() - 15.0).abs() < 1e-9);
+        assert!(clamped.is_finished() && unclamped.is_finished());
+    }
+
+    #[test]
+    fn vec_lerp_into_reuses_the_output() {
+        let start = vec![0.0, 10.0, 20.0];
+        let end = vec![10.0, 0.0, 40.0];
+
+        let mut out = Vec::with_capacity(8);
+        out.extend([f64::NAN; 5]);
+        let buffer = out.as_ptr();
+        Vec::lerp_into(&start, &end, 0.5, &mut out);
+        assert_eq!(out, [5.0, 5.0, 30.0]);
+        assert_eq!(out.as_ptr(), buffer);
+        assert_eq!(out, Vec::lerp(&start, &end, 0.5));
+
+        // Missing elements are appended, and the shortest input wins.
+        let mut out = vec![1.0];
+        Vec::lerp_into(&start, &end[..2].to_vec(), 0.25, &mut out);
+        assert_eq!(out, [2.5, 7.5]);
+    }
+
+    #[test]
+    fn catch_up_sub_steps() {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_catch_up(Duration::from_millis(16))
+            .with_on_update({
+                let updates = Arc::clone(&updates);
+                move |value| updates.lock().unwrap().push(value)
+            });
+        animation.tick(Duration::from_millis(50));
+        animation.tick(Duration::from_millis(200));
+        assert_eq!(
+            *updates.lock().unwrap(),
+            [0.16, 0.32, 0.48, 0.5, 0.66, 0.82, 0.98, 1.0]
+        );
+        assert!(animation.just_finished());
+    }
+
+    #[test]
+    fn catch_up_after_a_long_stall_is_capped() {
+        let updates = Arc::new(Mutex::new(0_u32));
+        // A spring without damping never finishes.
+        let mut animation = Animation::headless(0.0, 1.0, Duration::ZERO)
+            .with_spring(0.0, 200.0)
+            .with_catch_up(Duration::from_millis(1))
+            .with_on_update({
+                let updates = Arc::clone(&updates);
+                move |_| *updates.lock().unwrap() += 1
+            });
+        let now = Duration::from_secs(24 * 60 * 60);
+        animation.tick(now);
+        assert_eq!(*updates.lock().unwrap(), MAX_CATCH_UP_STEPS + 1);
+        assert_eq!(animation.elapsed_effective(), now);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn monotonic_time_never_goes_back() {
+        let before = get_monotonic_time();
+        let after = get_monotonic_time();
+        assert!(before <= after);
+    }
+
+    // Run with `wasm-pack test --headless --firefox`, performance.now() needs a browser window.
+    #[cfg(target_arch = "wasm32")]
+    mod wasm {
+        use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+        use super::*;
+
+        wasm_bindgen_test_configure!(run_in_browser);
+
+        #[wasm_bindgen_test]
+        fn monotonic_time_never_goes_back() {
+            let before = get_monotonic_time();
+            let after = get_monotonic_time();
+            assert!(before <= after);
+            assert!(after > Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn validate_reports_each_error() {
+        let animation = |curve: AnimationCurve| {
+            Animation::headless(0.0, 1.0, Duration::from_millis(100)).with_curve(curve)
+        };
+        let spring = |mass, damping_ratio, stiffness| {
+            SpringCurve::new(0.0, false, mass, damping_ratio, stiffness, None).into()
+        };
+
+        assert_eq!(animation(AnimationCurve::default()).validate(), Ok(()));
+        assert_eq!(animation(spring(1.0, 0.8, 300.0)).validate(), Ok(()));
+
+        let zero = Animation::headless(0.0, 1.0, Duration::ZERO);
+        assert_eq!(zero.validate(), Err(AnimationError::ZeroDuration));
+
+        let non_finite = [
+            curve::Easing::EaseOutBackCustom(f64::NAN).into(),
+            CubicCurve::new((0.25, f64::INFINITY), (0.25, 1.0)).into(),
+            spring(1.0, 0.8, f64::NAN),
+            AnimationCurve::BlendEasings {
+                a: curve::Easing::Linear,
+                b: curve::Easing::EaseIn,
+                weight: f64::NAN,
+            },
+            curve::piecewise::Curve::new(vec![(0.0, 0.0), (1.0, f64::NAN)])
+                .unwrap()
+                .into(),
+        ];
+        for curve in non_finite {
+            let error = animation(curve.clone()).validate();
+            assert_eq!(error, Err(AnimationError::NonFiniteParameter), "{curve:?}");
+        }
+
+        let cubic = CubicCurve::new((1.5, 0.0), (0.25, 1.0));
+        let error = animation(cubic.into()).validate();
+        assert_eq!(error, Err(AnimationError::ControlPointOutOfRange));
+
+        for spring in [
+            spring(1.0, 0.0, 300.0),
+            spring(0.0, 0.8, 300.0),
+            spring(1.0, 0.8, 0.0),
+        ] {
+            let error = animation(spring).validate();
+            assert_eq!(error, Err(AnimationError::DivergentSpring));
+        }
+
+        let mut springy = animation(spring(1.0, 0.8, 300.0));
+        let error = springy.try_set_duration(Duration::from_secs(1));
+        assert_eq!(error, Err(AnimationError::CurveMismatch));
+    }
+
+    #[test]
+    fn ticking_before_the_start_does_not_panic() {
+        let ms = Duration::from_millis;
+        let animations = [
+            Animation::headless(0.0, 1.0, ms(100)).with_delay(ms(50)),
+            Animation::headless(0.0, 1.0, ms(100))
+                .with_delay(ms(50))
+                .with_speed(2.0),
+            Animation::headless(0.0, 1.0, ms(100))
+                .with_delay(ms(50))
+                .with_catch_up(ms(10)),
+        ];
+        for mut animation in animations {
+            animation.tick(ms(40));
+            // An out-of-order timestamp, before both the start and the last tick.
+            animation.tick(ms(10));
+            assert_eq!(*animation.value(), 0.0);
+            assert_eq!(animation.progress(), 0.0);
+
+            animation.tick(ms(500));
+            assert!(animation.is_finished());
+            assert_eq!(*animation.value(), 1.0);
+        }
+    }
+
+    #[test]
+    fn constant_speed_scales_with_distance() {
+        let near = Animation::headless(0.0, 100.0, Duration::ZERO).with_constant_speed(500.0);
+        let far = Animation::headless(0.0, 200.0, Duration::ZERO).with_constant_speed(500.0);
+        assert_eq!(near.total_duration(), Duration::from_millis(200));
+        assert_eq!(far.total_duration(), near.total_duration() * 2);
+
+        // The direction doesn't matter, only how far the value travels.
+        let back = Animation::headless(100.0, 0.0, Duration::ZERO).with_constant_speed(500.0);
+        assert_eq!(back.total_duration(), near.total_duration());
+    }
+
+    #[test]
+    fn peek_holds_at_the_pause_target() {
+        let linear = AnimationCurve::Simple(curve::Easing::Linear);
+        let mut animation = Animation::headless(0.0, 1.0, Duration::from_millis(100))
+            .with_curve(linear)
+            .with_pause_at(0.5);
+        assert_eq!(animation.peek(Duration::from_millis(30)), 0.3);
+        assert_eq!(animation.peek(Duration::from_millis(80)), 0.5);
+
+        animation.tick(Duration::from_millis(80));
+        assert_eq!(*animation.value(), 0.5);
+        assert_eq!(animation.state(), AnimationState::Paused);
+    }
+
+    #[test]
+    fn repeats_count_towards_finishing_and_peeking() {
+        let ms = Duration::from_millis;
+        let linear = AnimationCurve::Simple(curve::Easing::Linear);
+        let mut animation = Animation::headless(0.0, 1.0, ms(100))
+            .with_curve(linear)
+            .with_repeat(2)
+            .with_repeat_delay(ms(50));
+
+        // Holding at the end between the iterations, then playing the next one.
+        assert_eq!(animation.peek(ms(120)), 1.0);
+        assert_eq!(animation.peek(ms(200)), 0.5);
+        assert_eq!(animation.peek(ms(350)), 0.5);
+        assert_eq!(animation.peek(ms(500)), 1.0);
+
+        animation.tick(ms(120));
+        assert!(!animation.is_finished());
+        animation.set_state(AnimationState::Paused);
+        assert!(!animation.is_finished());
+        animation.set_state(AnimationState::Running);
+
+        animation.tick(ms(200));
+        assert_eq!(*animation.value(), animation.peek(ms(200)));
+        animation.tick(ms(400));
+        assert!(animation.is_finished());
+    }
+
+    #[test]
+    fn config_eq_ignores_the_clock_and_state() {
+        let ms = Duration::from_millis;
+        let config = || {
+            Animation::headless(0.0, 1.0, ms(100))
+                .with_name("fade")
+                .with_pause_at(0.5)
+                .with_bounds(0.0, 1.0)
+        };
+        let mut running = config();
+        running.tick(ms(30));
+        let mut paused = config().with_state(AnimationState::Paused);
+        paused.tick(ms(80));
+        assert!(running.config_eq(&paused));
+
+        assert!(!config().config_eq(&config().with_name("slide")));
+        assert!(!config().config_eq(&config().with_pause_at(0.6)));
+        assert!(!config().config_eq(&config().with_bounds(0.0, 0.9)));
+        assert!(!config().config_eq(&config().with_catch_up(ms(10))));
+
+        // Seeding a spring from an in-flight state doesn't change its configuration.
+        let spring = SpringCurve::new(0.0, false, 1.0, 0.8, 300.0, None);
+        let seeded = config().with_curve(spring.with_initial_position(0.4));
+        assert!(config().with_curve(spring).config_eq(&seeded));
+        let stiffer = SpringCurve::new(0.0, false, 1.0, 0.8, 400.0, None);
+        assert!(!config().with_curve(stiffer).config_eq(&seeded));
+    }
+
+    #[test]
+    fn eased_progress_matches_the_value() {
+        let ms = Duration::from_millis;
+        let mut animation = Animation::headless(0.0, 1.0, ms(100))
+            .with_curve(AnimationCurve::Simple(curve::Easing::EaseInOutQuad));
+        for millis in [0, 25, 50, 99, 100, 150] {
+            animation.tick(ms(millis));
+            assert_eq!(
+                animation.eased_progress(),
+                *animation.value(),
+                "at {millis}ms"
+            );
+        }
+
+        let mut instant = Animation::headless(0.0, 1.0, Duration::ZERO);
+        assert_eq!(instant.eased_progress(), 1.0);
+        instant.tick(ms(10));
+        assert_eq!(instant.eased_progress(), 1.0);
+        let unclamped = Animation::headless(0.0, 1.0, Duration::ZERO).with_unclamped(true);
+        assert_eq!(unclamped.eased_progress(), 1.0);
+    }
+
+    #[test]
+    fn restart_from_current_skips_the_delay() {
+        let ms = Duration::from_millis;
+        let linear = AnimationCurve::Simple(curve::Easing::Linear);
+        let mut animation = Animation::headless(0.0, 10.0, ms(1000))
+            .with_curve(linear)
+            .with_delay(ms(500));
+        animation.tick(ms(1000));
+        assert_eq!(*animation.value(), 5.0);
+
+        animation.restart_from_current();
+        animation.tick(ms(1000));
+        assert_eq!(*animation.value(), 5.0);
+        // The animation keeps moving right away, from its current value.
+        animation.tick(ms(1200));
+        assert_eq!(*animation.value(), 6.0);
+        animation.tick(ms(2000));
+        assert_eq!(*animation.value(), 10.0);
+        assert!(animation.is_finished());
+    }
+
+    #[test]
+    fn finished_animations_apply_changes_on_restart() {
+        let ms = Duration::from_millis;
+        let mut animation = Animation::headless(0.0, 1.0, ms(100))
+            .with_curve(AnimationCurve::Simple(curve::Easing::Linear));
+        animation.tick(ms(100));
+        assert_eq!(animation.state(), AnimationState::Finished);
+
+        animation.set_duration(ms(400));
+        animation.tick(ms(200));
+        assert_eq!(animation.state(), AnimationState::Finished);
+        assert_eq!(*animation.value(), 1.0);
+
+        animation.restart_at(ms(200));
+        animation.tick(ms(300));
+        assert_eq!(animation.state(), AnimationState::Running);
+        assert_eq!(*animation.value(), 0.25);
+    }
+
+    #[test]
+    fn zero_length_cycles_skip_straight_to_the_last_iteration() {
+        let loops = Arc::new(Mutex::new(Vec::new()));
+        let counter = Arc::clone(&loops);
+        let mut animation = Animation::headless(0.0, 1.0, Duration::ZERO)
+            .with_repeat(u32::MAX - 1)
+            .with_on_loop(move |index| counter.lock().unwrap().push(index));
+        animation.tick(Duration::from_millis(1));
+        assert!(animation.is_finished());
+        assert_eq!(*animation.value(), 1.0);
+
+        // Only the last iterations are reported.
+        let loops = loops.lock().unwrap();
+        assert_eq!(loops.len(), MAX_LOOP_CALLBACKS as usize);
+        assert_eq!(loops.last(), Some(&(u32::MAX - 2)));
+    }
+
+    #[test]
+    fn speed_changes_keep_the_value_and_wall_time() {
+        let ms = Duration::from_millis;
+        let linear = AnimationCurve::Simple(curve::Easing::Linear);
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        let mut animation = Animation::headless(0.0, 1.0, ms(1000)).with_curve(linear.clone());
+        animation.tick(ms(100));
+        assert!(close(*animation.value(), 0.1));
+
+        animation.set_speed(2.0);
+        animation.tick(ms(100));
+        assert!(close(*animation.value(), 0.1));
+        animation.tick(ms(200));
+        assert!(close(*animation.value(), 0.3));
+        assert_eq!(animation.elapsed_real(), ms(200));
+
+        animation.set_speed(1.0);
+        animation.tick(ms(200));
+        assert!(close(*animation.value(), 0.3));
+        animation.tick(ms(300));
+        assert!(close(*animation.value(), 0.4));
+        assert_eq!(animation.elapsed_real(), ms(300));
+
+        // Playing faster right from the clock origin doesn't lose any progress.
+        let mut animation = Animation::headless(0.0, 1.0, ms(1000))
+            .with_curve(linear)
+            .with_speed(2.0);
+        animation.tick(ms(10));
+        assert!(close(*animation.value(), 0.02));
+        animation.tick(ms(20));
+        assert!(close(*animation.value(), 0.04));
+        assert_eq!(animation.elapsed_real(), ms(20));
+        assert!(close(animation.peek(ms(30)), 0.06));
+
+        // The same goes when catching up on missed frames.
+        let mut animation = Animation::headless(0.0, 1.0, ms(1000))
+            .with_curve(AnimationCurve::Simple(curve::Easing::Linear))
+            .with_speed(2.0)
+            .with_catch_up(ms(5));
+        animation.tick(ms(20));
+        assert!(close(*animation.value(), 0.04));
+        assert_eq!(animation.elapsed_real(), ms(20));
+    }
+
+    #[test]
+    fn tiny_changes_keep_the_value_and_add_up() {
+        let mut animation = Animation::headless(0.0_f64, 1.0, Duration::from_millis(1000))
+            .with_curve(curve::Easing::Linear)
+            .with_value_epsilon(0.01);
+        assert!(!animation.tick_changed(Duration::from_millis(6)));
+        assert!((animation.value() - 0.006).abs() < 1e-9);
+        // 0.006 + 0.006 since the last reported value.
+        assert!(animation.tick_changed(Duration::from_millis(12)));
+        assert!(!animation.tick_changed(Duration::from_millis(18)));
+
+        let mut ticked = animation.clone();
+        ticked.tick(Duration::from_millis(20));
+        assert!(!animation.tick_changed(Duration::from_millis(20)));
+        assert_eq!(animation.value(), ticked.value());
+    }
+}
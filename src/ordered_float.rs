@@ -0,0 +1,62 @@
+//! Animation implementation for [`ordered_float`] wrappers.
+
+use ordered_float::{NotNan, OrderedFloat};
+
+use crate::Animable;
+
+impl<T: Animable> Animable for OrderedFloat<T> {
+    fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+        Self(T::lerp(&start.0, &end.0, progress))
+    }
+}
+
+// The interpolated value can be NaN with extreme progress values, for example when an infinite
+// progress is multiplied by a zero distance. In that case, we snap to the closest endpoint.
+macro_rules! not_nan_impl {
+    ($t:ty) => {
+        impl Animable for NotNan<$t> {
+            fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+                let value = <$t>::lerp(&start.into_inner(), &end.into_inner(), progress);
+                Self::new(value).unwrap_or(if progress < 0.5 { *start } else { *end })
+            }
+        }
+    };
+}
+
+not_nan_impl!(f32);
+not_nan_impl!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_nan_is_never_nan() {
+        let value = |start: f64, end: f64, progress: f64| {
+            let (start, end) = (NotNan::new(start).unwrap(), NotNan::new(end).unwrap());
+            NotNan::<f64>::lerp(&start, &end, progress).into_inner()
+        };
+        assert_eq!(value(1.0, 3.0, 0.5), 2.0);
+
+        // Zero distance multiplied by an infinite or NaN progress.
+        assert_eq!(value(1.0, 1.0, f64::INFINITY), 1.0);
+        assert_eq!(value(1.0, 1.0, f64::NEG_INFINITY), 1.0);
+        assert_eq!(value(1.0, 2.0, f64::NAN), 2.0);
+        // Infinite endpoints.
+        assert_eq!(
+            value(f64::NEG_INFINITY, f64::INFINITY, 0.25),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(value(f64::NEG_INFINITY, f64::INFINITY, 0.75), f64::INFINITY);
+
+        let start = NotNan::new(0.0_f32).unwrap();
+        let value = NotNan::<f32>::lerp(&start, &start, f64::INFINITY);
+        assert_eq!(value, start);
+    }
+
+    #[test]
+    fn ordered_float_lerps_the_inner_value() {
+        let value = OrderedFloat::lerp(&OrderedFloat(1.0_f64), &OrderedFloat(3.0), 0.25);
+        assert_eq!(value, OrderedFloat(1.5));
+    }
+}
@@ -0,0 +1,214 @@
+//! A keyed collection of animations.
+//!
+//! An [`AnimationSet`] handles the common bookkeeping of ticking a lot of animations at once, and
+//! pruning the ones that finished.
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use fht_animation::set::AnimationSet;
+//! use fht_animation::Animation;
+//!
+//! let mut set = AnimationSet::new();
+//! set.insert("window-1", Animation::new(0.0, 1.0, Duration::from_millis(200)));
+//! set.insert("window-2", Animation::new(1.0, 0.0, Duration::from_millis(300)));
+//!
+//! set.tick_all(fht_animation::get_monotonic_time());
+//! for (key, _animation) in set.remove_finished() {
+//!     println!("{key} finished animating!");
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+use crate::{Animable, Animation, AnimationState};
+
+/// A collection of animations, identified by a key.
+pub struct AnimationSet<K, T: Animable> {
+    animations: HashMap<K, Animation<T>>,
+}
+
+impl<K, T: Animable> Default for AnimationSet<K, T> {
+    fn default() -> Self {
+        Self {
+            animations: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, T: Animable> AnimationSet<K, T> {
+    /// Create a new empty animation set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert an animation in the set, returning the previous animation with the same key, if any.
+    pub fn insert(&mut self, key: K, animation: Animation<T>) -> Option<Animation<T>> {
+        self.animations.insert(key, animation)
+    }
+
+    /// Remove the animation with the given key from the set.
+    pub fn remove(&mut self, key: &K) -> Option<Animation<T>> {
+        self.animations.remove(key)
+    }
+
+    /// Get the animation with the given key.
+    pub fn get(&self, key: &K) -> Option<&Animation<T>> {
+        self.animations.get(key)
+    }
+
+    /// Get a mutable reference to the animation with the given key.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Animation<T>> {
+        self.animations.get_mut(key)
+    }
+
+    /// Tick all the animations of the set.
+    pub fn tick_all(&mut self, now: Duration) {
+        for animation in self.animations.values_mut() {
+            animation.tick(now);
+        }
+    }
+
+    /// Remove all the finished animations from the set, and return them.
+    ///
+    /// The state change callback of every removed animation is called with
+    /// [`AnimationState::Finished`], if it wasn't already, for example when it finished while
+    /// paused.
+    pub fn remove_finished(&mut self) -> Vec<(K, Animation<T>)> {
+        self.animations
+            .extract_if(|_, animation| finish(animation))
+            .collect()
+    }
+
+    /// Remove the finished animations for which `keep` returns `false`.
+    ///
+    /// Unlike [`AnimationSet::remove_finished`], this doesn't collect the removed animations, and
+    /// lets you keep some of the finished animations around, to restart them later for example.
+    /// The callbacks are called like with [`AnimationSet::remove_finished`].
+    pub fn retain_finished(&mut self, mut keep: impl FnMut(&K, &mut Animation<T>) -> bool) {
+        self.animations
+            .retain(|key, animation| !finish(animation) || keep(key, animation));
+    }
+
+    /// Iterate over all the animations of the set.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Animation<T>)> {
+        self.animations.iter()
+    }
+
+    /// Iterate over the animations of the set that are not finished yet.
+    pub fn iter_active(&self) -> impl Iterator<Item = (&K, &Animation<T>)> {
+        self.animations
+            .iter()
+            .filter(|(_, animation)| !animation.is_finished())
+    }
+
+    /// Get the number of animations in the set.
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    /// Check whether the set contains no animations.
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+}
+
+/// Switch `animation` to the finished state if it's finished, returning whether it is.
+fn finish<T: Animable>(animation: &mut Animation<T>) -> bool {
+    if !animation.is_finished() {
+        return false;
+    }
+
+    // A paused animation can be finished without being in the finished state.
+    if animation.state() != AnimationState::Finished {
+        animation.set_state(AnimationState::Finished);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn tick_all_and_remove_finished() {
+        let finished = Arc::new(Mutex::new(Vec::new()));
+        let animation = |key: &'static str, millis| {
+            let finished = Arc::clone(&finished);
+            Animation::headless(0.0, 1.0, Duration::from_millis(millis)).with_on_state_change(
+                move |state| {
+                    if state == AnimationState::Finished {
+                        finished.lock().unwrap().push(key);
+                    }
+                },
+            )
+        };
+
+        let mut set = AnimationSet::new();
+        set.insert("short", animation("short", 100));
+        set.insert("long", animation("long", 200));
+
+        set.tick_all(Duration::from_millis(50));
+        assert_eq!(*set.get(&"short").unwrap().value(), 0.5);
+        assert_eq!(*set.get(&"long").unwrap().value(), 0.25);
+        assert!(set.remove_finished().is_empty());
+
+        set.tick_all(Duration::from_millis(100));
+        assert_eq!(*finished.lock().unwrap(), ["short"]);
+        let removed = set.remove_finished();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].0, "short");
+        assert_eq!(*removed[0].1.value(), 1.0);
+        assert!(set.get(&"short").is_none());
+        assert_eq!(set.iter_active().count(), 1);
+
+        set.tick_all(Duration::from_millis(200));
+        assert_eq!(*finished.lock().unwrap(), ["short", "long"]);
+        assert_eq!(set.iter_active().count(), 0);
+        assert_eq!(set.remove_finished().len(), 1);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn pruning_fires_the_finished_callbacks() {
+        let states = Arc::new(Mutex::new(Vec::new()));
+        let animation = |key: &'static str| {
+            let states = Arc::clone(&states);
+            Animation::headless(0.0, 1.0, Duration::from_millis(100))
+                .with_on_state_change(move |state| states.lock().unwrap().push((key, state)))
+        };
+
+        let mut set = AnimationSet::new();
+        for key in ["kept", "paused", "running"] {
+            set.insert(key, animation(key));
+        }
+        set.get_mut(&"running")
+            .unwrap()
+            .set_duration(Duration::from_secs(1));
+        set.tick_all(Duration::from_millis(100));
+        // Finished before getting paused, so it never reports finishing on its own.
+        set.get_mut(&"paused")
+            .unwrap()
+            .set_state(AnimationState::Paused);
+        states.lock().unwrap().clear();
+
+        set.retain_finished(|key, _| *key == "kept");
+        assert!(set.get(&"kept").is_some() && set.get(&"running").is_some());
+        assert!(set.get(&"paused").is_none());
+        assert_eq!(
+            *states.lock().unwrap(),
+            [("paused", AnimationState::Finished)]
+        );
+
+        let removed = set.remove_finished();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].0, "kept");
+        // The kept animation already reported finishing, it doesn't report it twice.
+        assert_eq!(states.lock().unwrap().len(), 1);
+        assert_eq!(set.len(), 1);
+    }
+}
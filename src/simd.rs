@@ -0,0 +1,48 @@
+//! Animation implementation for portable SIMD vectors.
+//!
+//! This requires a nightly compiler, since `std::simd` is not stabilized yet.
+
+use std::simd::{f32x4, f32x8, f64x2, f64x4, Simd};
+
+use crate::Animable;
+
+// All the lanes are interpolated at once.
+macro_rules! simd_impl {
+    ($t:ty, $scalar:ty) => {
+        impl Animable for $t {
+            fn lerp(start: &Self, end: &Self, progress: f64) -> Self {
+                (*end - *start) * Simd::splat(progress as $scalar) + *start
+            }
+        }
+    };
+}
+
+simd_impl!(f32x4, f32);
+simd_impl!(f32x8, f32);
+simd_impl!(f64x2, f64);
+simd_impl!(f64x4, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lanes_lerp_like_scalars() {
+        let (start, end) = ([0.0, 1.5, -3.0, 100.0], [1.0, -1.5, 3.0, 0.0]);
+        for progress in [0.0, 0.3, 1.0, 1.25] {
+            let lanes = f64x4::lerp(&f64x4::from_array(start), &f64x4::from_array(end), progress);
+            let scalars: [f64; 4] =
+                std::array::from_fn(|i| f64::lerp(&start[i], &end[i], progress));
+            for (lane, scalar) in lanes.to_array().into_iter().zip(scalars) {
+                assert!((lane - scalar).abs() < 1e-12, "{lane} != {scalar}");
+            }
+        }
+
+        let lanes = f32x8::lerp(
+            &f32x8::splat(2.0),
+            &f32x8::from_array([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            0.5,
+        );
+        assert_eq!(lanes.to_array(), [1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5]);
+    }
+}